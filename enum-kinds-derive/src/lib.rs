@@ -0,0 +1,2738 @@
+#![doc = include_str!("../README.md")]
+
+extern crate proc_macro;
+extern crate proc_macro2;
+extern crate quote;
+#[macro_use]
+extern crate syn;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::HashSet;
+use syn::ext::IdentExt;
+use syn::punctuated::Punctuated;
+use syn::{
+    Attribute, Data, DataEnum, DeriveInput, Expr, ExprAssign, ExprLit, ExprParen, ExprPath,
+    ExprUnary, Fields, GenericParam, Ident, Lifetime, LifetimeDef, Lit, LitChar, LitStr, Meta,
+    MetaList, MetaNameValue, NestedMeta, Path, UnOp, Variant, WhereClause,
+};
+
+#[proc_macro_derive(
+    EnumKind,
+    attributes(
+        enum_kind,
+        enum_kind_char,
+        enum_kind_rename,
+        enum_kind_attr,
+        enum_kind_value,
+        enum_kind_alias,
+        enum_kind_skip,
+        enum_kind_group
+    )
+)]
+pub fn enum_kind(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse(input).expect("#[derive(EnumKind)] failed to parse input");
+    let spec = get_enum_specification(&ast);
+    let enum_ = create_kind_enum(&ast, &spec);
+    let impl_ = create_impl(&ast, &spec);
+    let original = create_original_methods(&ast, &spec);
+    let index = create_index_conversions(&ast, &spec);
+    let chars = create_char_conversions(&ast, &spec);
+    let default = create_default_impl(&ast, &spec);
+    let repr = create_repr_methods(&ast, &spec);
+    let toggle = create_toggle(&ast, &spec);
+    let all = create_all_variants(&ast, &spec);
+    let names = create_name_methods(&ast, &spec);
+    let map = create_kind_map(&ast, &spec);
+    let set = create_kind_set(&ast, &spec);
+    let array = create_kind_array(&ast, &spec);
+    let eq = create_eq_by_name(&spec);
+    let conversions = create_conversions(&spec);
+    let serde = create_serde_as_index(&spec);
+    let valuable = create_valuable(&ast, &spec);
+    let ord = create_ord(&spec);
+    let by_kind = create_original_by_kind(&ast, &spec);
+    let groups = create_group_kind(&ast, &spec);
+    let in_group = create_in_group(&ast, &spec);
+    let predicates = create_predicates(&ast, &spec);
+    let code = quote! {
+        #enum_
+        #impl_
+        #original
+        #index
+        #chars
+        #default
+        #repr
+        #toggle
+        #all
+        #names
+        #map
+        #set
+        #array
+        #eq
+        #conversions
+        #serde
+        #valuable
+        #ord
+        #by_kind
+        #groups
+        #in_group
+        #predicates
+    };
+    proc_macro::TokenStream::from(code)
+}
+
+fn find_attribute(
+    attrs: &[Attribute],
+    name: &str,
+) -> Option<Punctuated<NestedMeta, syn::token::Comma>> {
+    for attr in attrs.iter() {
+        match attr.parse_meta() {
+            Ok(Meta::List(MetaList {
+                ref path,
+                ref nested,
+                ..
+            })) if path.is_ident(name) => return Some(nested.clone()),
+            _ => continue,
+        }
+    }
+    None
+}
+
+// A name such as `SomeKind<T>` is not a valid meta item, so `find_attribute`
+// skips the attribute. The kind enum never has generic parameters, so this is
+// reported explicitly instead of as a missing attribute.
+fn reject_generic_name(attrs: &[Attribute]) {
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("enum_kind")) {
+        let name = attr.parse_args_with(|input: syn::parse::ParseStream| {
+            let name: Path = input.parse()?;
+            input.parse::<TokenStream>()?;
+            Ok(name)
+        });
+        if let Ok(name) = name {
+            if name
+                .segments
+                .iter()
+                .any(|segment| !segment.arguments.is_empty())
+            {
+                panic!(
+                    "#[enum_kind(NAME)] requires NAME to be a plain name, found {}; the generated enum is never generic",
+                    quote!(#name).to_string().replace(' ', "")
+                );
+            }
+        }
+    }
+}
+
+struct Specification {
+    name: Path,
+    traits: Vec<NestedMeta>,
+    no_copy: bool,
+    index_type: Ident,
+    auto_default: bool,
+    toggle: bool,
+    inherit_docs: bool,
+    doc_alias: bool,
+    repr: Option<Ident>,
+    bound: Option<WhereClause>,
+    lifetime: Option<String>,
+    self_test: bool,
+    catch_all: Option<Ident>,
+    catch_all_char: Option<LitChar>,
+    map: bool,
+    eq_by_name: bool,
+    inherent_only: bool,
+    owned_only: bool,
+    fallible: bool,
+    mirror_repr: bool,
+    predicates: bool,
+    no_debug: bool,
+    hash: bool,
+    serde_as_index: bool,
+    ord: bool,
+    display: bool,
+    name_style: Option<NameStyle>,
+    default_variant: Option<Ident>,
+    hash_original_by_kind: bool,
+    std_discriminant: bool,
+    with_kind: bool,
+    serde_crate: Option<LitStr>,
+    set: bool,
+    array: bool,
+    conversions: bool,
+    valuable: bool,
+    debug_with_value: bool,
+    group_kind: Option<Ident>,
+}
+
+fn get_enum_specification(definition: &DeriveInput) -> Specification {
+    let params = find_attribute(&definition.attrs, "enum_kind").unwrap_or_else(|| {
+        reject_generic_name(&definition.attrs);
+        panic!("#[derive(EnumKind)] requires an associated enum_kind attribute to be specified")
+    });
+    let mut iter = params.iter();
+    let name = match iter.next() {
+        Some(NestedMeta::Meta(Meta::Path(path))) => path.to_owned(),
+        Some(NestedMeta::Lit(Lit::Str(value))) => {
+            Path::from(value.parse::<Ident>().unwrap_or_else(|_| {
+                panic!(
+                    "#[enum_kind(\"NAME\")] requires NAME to be an identifier, found {:?}",
+                    value.value()
+                )
+            }))
+        }
+        _ => panic!("#[enum_kind(NAME)] attribute requires NAME to be specified"),
+    };
+    let mut spec = Specification {
+        name,
+        traits: Vec::new(),
+        no_copy: false,
+        index_type: format_ident!("usize"),
+        auto_default: false,
+        toggle: false,
+        inherit_docs: false,
+        doc_alias: false,
+        repr: None,
+        bound: None,
+        lifetime: None,
+        self_test: false,
+        catch_all: None,
+        catch_all_char: None,
+        map: false,
+        eq_by_name: false,
+        inherent_only: false,
+        owned_only: false,
+        fallible: false,
+        mirror_repr: false,
+        predicates: false,
+        no_debug: false,
+        hash: false,
+        serde_as_index: false,
+        ord: false,
+        display: false,
+        name_style: None,
+        default_variant: None,
+        hash_original_by_kind: false,
+        std_discriminant: false,
+        with_kind: false,
+        serde_crate: None,
+        set: false,
+        array: false,
+        conversions: false,
+        valuable: false,
+        debug_with_value: false,
+        group_kind: None,
+    };
+    let mut derive_all = false;
+    for param in iter {
+        match param {
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("no_copy") => spec.no_copy = true,
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("no_debug") => spec.no_debug = true,
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("auto_default") => {
+                spec.auto_default = true
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("toggle") => spec.toggle = true,
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("inherit_docs") => {
+                spec.inherit_docs = true
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("doc_alias") => {
+                spec.doc_alias = true
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("self_test") => {
+                spec.self_test = true
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("map") => spec.map = true,
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("set") => spec.set = true,
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("array") => spec.array = true,
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("conversions") => {
+                spec.conversions = true
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("valuable") => spec.valuable = true,
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("debug_with_value") => {
+                spec.debug_with_value = true
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("hash") => spec.hash = true,
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("ord") => spec.ord = true,
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("hash_original_by_kind") => {
+                spec.hash_original_by_kind = true
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("std_discriminant") => {
+                spec.std_discriminant = true
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("with_kind") => {
+                spec.with_kind = true
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("display") => spec.display = true,
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("serde_as_index") => {
+                if !cfg!(feature = "serde") {
+                    panic!("#[enum_kind(NAME, serde_as_index)] requires the serde feature of enum-kinds");
+                }
+                spec.serde_as_index = true
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("eq_by_name") => {
+                spec.eq_by_name = true
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("inherent_only") => {
+                spec.inherent_only = true
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("owned_only") => {
+                spec.owned_only = true
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("fallible") => spec.fallible = true,
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("mirror_repr") => {
+                spec.mirror_repr = true
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("predicates") => {
+                spec.predicates = true
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("catch_all") => {
+                spec.catch_all = Some(format_ident!("Other"))
+            }
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(value),
+                ..
+            })) if path.is_ident("name_style") => {
+                spec.name_style = Some(match value.value().as_str() {
+                    "snake" => NameStyle::Snake,
+                    "kebab" => NameStyle::Kebab,
+                    "SCREAMING_SNAKE" => NameStyle::ScreamingSnake,
+                    "camel" => NameStyle::Camel,
+                    "pascal" => NameStyle::Pascal,
+                    _ => panic!("#[enum_kind(NAME, name_style = \"STYLE\")] requires STYLE to be one of snake, kebab, SCREAMING_SNAKE, camel or pascal"),
+                });
+            }
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(value),
+                ..
+            })) if path.is_ident("group_kind") => {
+                spec.group_kind = Some(value.parse().expect(
+                    "#[enum_kind(NAME, group_kind = \"GROUP\")] requires GROUP to be an identifier",
+                ));
+            }
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(value),
+                ..
+            })) if path.is_ident("default_variant") => {
+                spec.default_variant = Some(value.parse().expect(
+                    "#[enum_kind(NAME, default_variant = \"VARIANT\")] requires VARIANT to be an identifier",
+                ));
+            }
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(value),
+                ..
+            })) if path.is_ident("catch_all") => {
+                spec.catch_all = Some(value.parse().expect(
+                    "#[enum_kind(NAME, catch_all = \"VARIANT\")] requires VARIANT to be an identifier",
+                ));
+            }
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Char(value),
+                ..
+            })) if path.is_ident("catch_all_char") => spec.catch_all_char = Some(value.clone()),
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("derive_all") => derive_all = true,
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(value),
+                ..
+            })) if path.is_ident("serde_crate") => {
+                if value.parse::<Path>().is_err() {
+                    panic!("#[enum_kind(NAME, serde_crate = \"PATH\")] requires PATH to be a path");
+                }
+                spec.serde_crate = Some(value.clone());
+            }
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(value),
+                ..
+            })) if path.is_ident("repr") => {
+                let repr = value
+                    .parse()
+                    .ok()
+                    .filter(|ty| integer_type_max(ty).is_some());
+                spec.repr = Some(repr.expect(
+                    "#[enum_kind(NAME, repr = \"TYPE\")] requires TYPE to be an integer type",
+                ));
+            }
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(value),
+                ..
+            })) if path.is_ident("bound") => {
+                let bound = syn::parse_str(&format!("where {}", value.value()));
+                spec.bound = Some(bound.expect(
+                    "#[enum_kind(NAME, bound = \"BOUND\")] requires BOUND to be a list of where predicates",
+                ));
+            }
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(value),
+                ..
+            })) if path.is_ident("lifetime") => {
+                let name = value.value();
+                let name = name.trim_start_matches('\'');
+                if syn::parse_str::<Lifetime>(&format!("'{}", name)).is_err() {
+                    panic!("#[enum_kind(NAME, lifetime = \"LIFETIME\")] requires LIFETIME to be a valid lifetime name");
+                }
+                spec.lifetime = Some(name.to_owned());
+            }
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, .. }))
+                if path.is_ident("reexport_path") =>
+            {
+                // Derive output is always expanded next to the enum, so there is
+                // no way to place a `pub use` inside another module from here.
+                panic!("#[enum_kind(NAME, reexport_path = \"PATH\")] is not supported; add `pub use` items for the kinds to the module at PATH instead");
+            }
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(value),
+                ..
+            })) if path.is_ident("index_type") => {
+                spec.index_type = value.parse().expect(
+                    "#[enum_kind(NAME, index_type = \"TYPE\")] requires TYPE to be an integer type",
+                );
+            }
+            _ => spec.traits.push(param.clone()),
+        }
+    }
+    if derive_all {
+        // Traits that are already derived, or implemented manually by `ord`, are
+        // skipped so the shorthand never produces conflicting implementations.
+        let mut implied: Vec<Path> = Vec::new();
+        let skipped: &[&str] = if spec.ord {
+            &["PartialOrd", "Ord"]
+        } else {
+            &[]
+        };
+        for name in &["Hash", "PartialOrd", "Ord"] {
+            if !derives(&spec.traits, name) && !skipped.contains(name) {
+                let ident = format_ident!("{}", name);
+                implied.push(parse_quote!(#ident));
+            }
+        }
+        if cfg!(feature = "serde") {
+            let serde = serde_path(&spec);
+            for name in &["Serialize", "Deserialize"] {
+                if !derives(&spec.traits, name) {
+                    let ident = format_ident!("{}", name);
+                    implied.push(parse_quote!(#serde::#ident));
+                }
+            }
+        }
+        if !implied.is_empty() {
+            spec.traits.push(parse_quote!(derive(#(#implied),*)));
+        }
+    }
+    if let Some(bound) = &spec.bound {
+        // Bounds are placed on the kind enum itself and copied to every impl
+        // derived for it. The kind enum has no generic parameters, so a bound
+        // that names it can only be proven through those same impls, which
+        // either overflows, as with `SomeEnumKind: Copy`, or is simply unmet.
+        if mentions_ident(quote! { #bound }, kind_name(&spec.name)) {
+            panic!(
+                "#[enum_kind(NAME, bound = \"BOUND\")] cannot refer to the generated enum {} itself, since every implementation for it would have to prove the bound",
+                kind_name(&spec.name)
+            );
+        }
+    }
+    if spec.default_variant.is_some() {
+        if spec.auto_default {
+            panic!("#[enum_kind(NAME, default_variant = \"VARIANT\")] cannot be combined with auto_default");
+        }
+        if !derives(&spec.traits, "Default") {
+            spec.traits.push(parse_quote!(derive(Default)));
+        }
+    }
+    if spec.hash && spec.repr.is_none() && !derives(&spec.traits, "Hash") {
+        // `Eq` is always derived unless `eq_by_name` replaces it, in which case the
+        // derive is stripped below in favour of hashing by name. With a `repr`, the
+        // kind hashes its discriminant instead, see `create_repr_methods`.
+        spec.traits.push(parse_quote!(derive(Hash)));
+    }
+    if spec.mirror_repr {
+        // The kind enum is only layout compatible with the original when it has
+        // the same representation and exactly the same discriminants.
+        if spec.repr.is_some() || spec.catch_all.is_some() {
+            panic!("#[enum_kind(NAME, mirror_repr)] cannot be combined with repr or catch_all");
+        }
+        let repr = find_attribute(&definition.attrs, "repr").and_then(|params| {
+            params.iter().find_map(|param| match param {
+                NestedMeta::Meta(Meta::Path(path)) => path
+                    .get_ident()
+                    .filter(|ident| integer_type_max(ident).is_some())
+                    .cloned(),
+                _ => None,
+            })
+        });
+        spec.repr = Some(repr.expect(
+            "#[enum_kind(NAME, mirror_repr)] requires the original enum to have an integer #[repr]",
+        ));
+        for variant in get_variants(definition).iter() {
+            if !matches!(variant.fields, Fields::Unit) {
+                panic!(
+                    "#[enum_kind(NAME, mirror_repr)] requires every variant to be a unit variant, found {}",
+                    variant.ident
+                );
+            }
+            if is_skipped(variant) {
+                panic!(
+                    "#[enum_kind(NAME, mirror_repr)] cannot be combined with #[enum_kind_skip] on {}",
+                    variant.ident
+                );
+            }
+            if get_variant_value(variant).code.is_some() {
+                panic!(
+                    "#[enum_kind(NAME, mirror_repr)] cannot be combined with #[enum_kind_value] code on {}",
+                    variant.ident
+                );
+            }
+        }
+    }
+    if spec.owned_only && spec.inherent_only {
+        panic!("#[enum_kind(NAME, owned_only)] cannot be combined with inherent_only");
+    }
+    if spec.catch_all_char.is_some() && spec.catch_all.is_none() {
+        panic!("#[enum_kind(NAME, catch_all_char = CHAR)] requires a catch-all variant to be specified with catch_all");
+    }
+    if spec.debug_with_value {
+        if spec.repr.is_none() {
+            panic!("#[enum_kind(NAME, debug_with_value)] requires a repr to be specified with repr = \"TYPE\"");
+        }
+        if spec.no_debug {
+            panic!("#[enum_kind(NAME, debug_with_value)] cannot be combined with no_debug");
+        }
+    }
+    if spec.ord && (derives(&spec.traits, "PartialOrd") || derives(&spec.traits, "Ord")) {
+        // Derived orderings compare discriminants, which can disagree with the
+        // declaration order used by `ord`, so neither is silently preferred.
+        panic!("#[enum_kind(NAME, ord)] conflicts with derived PartialOrd or Ord");
+    }
+    if spec.eq_by_name {
+        // Equality and hashing are implemented manually to keep them consistent.
+        spec.traits = strip_derives(&spec.traits, &["PartialEq", "Eq", "Hash"]);
+    }
+    if spec.serde_as_index {
+        spec.traits = strip_derives(&spec.traits, &["Serialize", "Deserialize"]);
+    }
+    if let Some(serde_crate) = &spec.serde_crate {
+        if derives(&spec.traits, "Serialize") || derives(&spec.traits, "Deserialize") {
+            spec.traits.push(parse_quote!(serde(crate = #serde_crate)));
+        }
+    }
+    spec
+}
+
+fn serde_path(spec: &Specification) -> Path {
+    match &spec.serde_crate {
+        Some(serde_crate) => serde_crate.parse().unwrap(),
+        None => parse_quote!(::serde),
+    }
+}
+
+fn mentions_ident(tokens: TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(other) => other == *ident,
+        proc_macro2::TokenTree::Group(group) => mentions_ident(group.stream(), ident),
+        _ => false,
+    })
+}
+
+fn strip_derives(traits: &[NestedMeta], names: &[&str]) -> Vec<NestedMeta> {
+    traits
+        .iter()
+        .filter_map(|attr| match attr {
+            NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("derive") => {
+                let mut list = list.clone();
+                list.nested = list
+                    .nested
+                    .into_iter()
+                    .filter(|derive| match derive {
+                        NestedMeta::Meta(Meta::Path(path)) => !path
+                            .segments
+                            .last()
+                            .is_some_and(|segment| names.iter().any(|name| segment.ident == name)),
+                        _ => true,
+                    })
+                    .collect();
+                if list.nested.is_empty() {
+                    None
+                } else {
+                    Some(NestedMeta::Meta(Meta::List(list)))
+                }
+            }
+            _ => Some(attr.clone()),
+        })
+        .collect()
+}
+
+fn get_variants(definition: &DeriveInput) -> &Punctuated<Variant, syn::token::Comma> {
+    match &definition.data {
+        Data::Enum(DataEnum { variants, .. }) => variants,
+        _ => panic!("#[derive(EnumKind)] is only allowed for enums"),
+    }
+}
+
+struct KindVariant<'a> {
+    ident: Ident,
+    /// The variant of the original enum, or `None` for the catch-all variant.
+    source: Option<&'a Variant>,
+}
+
+fn is_skipped(variant: &Variant) -> bool {
+    variant
+        .attrs
+        .iter()
+        .any(|attr| attr.path.is_ident("enum_kind_skip"))
+}
+
+fn get_kind_variants<'a>(
+    definition: &'a DeriveInput,
+    spec: &Specification,
+) -> Vec<KindVariant<'a>> {
+    let variants = get_variants(definition);
+    let mut kind_variants: Vec<KindVariant> = variants
+        .iter()
+        .filter(|v| !is_skipped(v))
+        .map(|v| KindVariant {
+            ident: kind_variant_ident(v),
+            source: Some(v),
+        })
+        .collect();
+    let mut seen = HashSet::new();
+    for variant in kind_variants.iter() {
+        if !seen.insert(&variant.ident) {
+            panic!(
+                "#[enum_kind_rename({})] conflicts with another variant",
+                variant.ident
+            );
+        }
+    }
+    match &spec.catch_all {
+        Some(catch_all) => {
+            if kind_variants.iter().any(|v| v.ident == *catch_all) {
+                panic!(
+                    "#[enum_kind(NAME, catch_all = \"{}\")] conflicts with an existing variant",
+                    catch_all
+                );
+            }
+            kind_variants.push(KindVariant {
+                ident: catch_all.clone(),
+                source: None,
+            });
+        }
+        None if kind_variants.len() != variants.len() && !spec.fallible => {
+            panic!("#[enum_kind_skip] requires a catch-all variant to be specified with #[enum_kind(NAME, catch_all)]")
+        }
+        None => {}
+    }
+    kind_variants
+}
+
+/// Returns `true` if some variants have no kind, which is only allowed with
+/// `fallible`. The infallible conversions are not generated in that case.
+fn is_partial(definition: &DeriveInput, spec: &Specification) -> bool {
+    spec.catch_all.is_none() && get_variants(definition).iter().any(is_skipped)
+}
+
+fn kind_variant_ident(variant: &Variant) -> Ident {
+    let params = match find_attribute(&variant.attrs, "enum_kind_rename") {
+        Some(params) => params,
+        None => return variant.ident.clone(),
+    };
+    match params.iter().collect::<Vec<_>>().as_slice() {
+        [NestedMeta::Meta(Meta::Path(path))] if path.get_ident().is_some() => {
+            path.get_ident().cloned().unwrap()
+        }
+        _ => panic!("#[enum_kind_rename(NAME)] requires NAME to be an identifier"),
+    }
+}
+
+fn variant_name(variant: &KindVariant, spec: &Specification) -> String {
+    let label = variant
+        .source
+        .and_then(|source| get_variant_value(source).label);
+    match (label, &spec.name_style) {
+        (Some(label), _) => label.value(),
+        (None, Some(style)) => style.apply(&variant.ident.to_string()),
+        (None, None) => variant.ident.to_string(),
+    }
+}
+
+enum NameStyle {
+    Snake,
+    Kebab,
+    ScreamingSnake,
+    Camel,
+    Pascal,
+}
+
+impl NameStyle {
+    fn apply(&self, ident: &str) -> String {
+        let words = split_words(ident);
+        let capitalized = || {
+            words.iter().map(|word| {
+                let mut chars = word.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                    .unwrap_or_default()
+            })
+        };
+        match self {
+            NameStyle::Snake => words.join("_"),
+            NameStyle::Kebab => words.join("-"),
+            NameStyle::ScreamingSnake => words.join("_").to_uppercase(),
+            NameStyle::Camel => {
+                let mut name = words.first().cloned().unwrap_or_default();
+                name.extend(capitalized().skip(1));
+                name
+            }
+            NameStyle::Pascal => capitalized().collect(),
+        }
+    }
+}
+
+// Splits an identifier into lowercase words at underscores and case changes,
+// keeping acronyms together: `HTTPServer_v2` becomes `http`, `server`, `v2`.
+fn split_words(ident: &str) -> Vec<String> {
+    let chars: Vec<char> = ident.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' {
+            if !word.is_empty() {
+                words.push(word.split_off(0));
+            }
+            continue;
+        }
+        if c.is_uppercase() && !word.is_empty() {
+            let previous = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if !previous.is_uppercase() || next_is_lower {
+                words.push(word.split_off(0));
+            }
+        }
+        word.extend(c.to_lowercase());
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+fn kind_name(kind_ident: &Path) -> &Ident {
+    &kind_ident
+        .segments
+        .last()
+        .expect("#[enum_kind(NAME)] attribute requires NAME to be specified")
+        .ident
+}
+
+fn std_crate() -> Path {
+    if cfg!(feature = "no-stdlib") {
+        parse_quote!(::core)
+    } else {
+        parse_quote!(::std)
+    }
+}
+
+fn integer_type_max(ty: &Ident) -> Option<u128> {
+    let max = match ty.to_string().as_str() {
+        "u8" => u8::MAX as u128,
+        "u16" => u16::MAX as u128,
+        "u32" => u32::MAX as u128,
+        "u64" => u64::MAX as u128,
+        "u128" => u128::MAX,
+        "usize" => usize::MAX as u128,
+        "i8" => i8::MAX as u128,
+        "i16" => i16::MAX as u128,
+        "i32" => i32::MAX as u128,
+        "i64" => i64::MAX as u128,
+        "i128" => i128::MAX as u128,
+        "isize" => isize::MAX as u128,
+        _ => return None,
+    };
+    Some(max)
+}
+
+fn receiver(spec: &Specification) -> (TokenStream, TokenStream) {
+    if spec.no_copy {
+        (quote! {&self}, quote! {&Self})
+    } else {
+        (quote! {self}, quote! {Self})
+    }
+}
+
+fn get_variant_char(variant: &Variant) -> Option<LitChar> {
+    let params = find_attribute(&variant.attrs, "enum_kind_char")?;
+    match params.iter().collect::<Vec<_>>().as_slice() {
+        [NestedMeta::Lit(Lit::Char(c))] => Some(c.clone()),
+        _ => panic!("#[enum_kind_char(CHAR)] requires a single character literal CHAR"),
+    }
+}
+
+#[derive(Default)]
+struct VariantValue {
+    code: Option<Expr>,
+    label: Option<LitStr>,
+    aliases: Vec<LitStr>,
+}
+
+fn get_variant_value(variant: &Variant) -> VariantValue {
+    let mut value = VariantValue::default();
+    let mut attrs = variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("enum_kind_value"));
+    let attr = match attrs.next() {
+        Some(attr) => attr,
+        None => return value,
+    };
+    if attrs.next().is_some() {
+        panic!(
+            "#[enum_kind_value] can only be given once, but {} has more than one",
+            variant.ident
+        );
+    }
+    let params = attr
+        .parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+        .expect("#[enum_kind_value(VALUE)] requires VALUE to be an expression");
+    let string = |expr: &Expr, key: &str| match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => lit.clone(),
+        _ => panic!(
+            "#[enum_kind_value({} = VALUE)] requires VALUE to be a string literal",
+            key
+        ),
+    };
+    let positional = params.len() == 1;
+    for param in params {
+        match param {
+            Expr::Assign(ExprAssign { left, right, .. }) => match &*left {
+                Expr::Path(ExprPath { path, .. }) if path.is_ident("code") => {
+                    value.code = Some(*right)
+                }
+                Expr::Path(ExprPath { path, .. }) if path.is_ident("label") => {
+                    value.label = Some(string(&right, "label"))
+                }
+                Expr::Path(ExprPath { path, .. }) if path.is_ident("alias") => {
+                    value.aliases.push(string(&right, "alias"))
+                }
+                _ => panic!("#[enum_kind_value(...)] accepts only code, label and alias keys"),
+            },
+            code if positional => value.code = Some(code),
+            _ => panic!("#[enum_kind_value(...)] requires either a single VALUE or named keys"),
+        }
+    }
+    value
+}
+
+fn get_variant_group(variant: &Variant) -> Option<Ident> {
+    let params = find_attribute(&variant.attrs, "enum_kind_group")?;
+    match params.iter().collect::<Vec<_>>().as_slice() {
+        [NestedMeta::Meta(Meta::Path(path))] if path.get_ident().is_some() => {
+            path.get_ident().cloned()
+        }
+        _ => panic!("#[enum_kind_group(GROUP)] requires GROUP to be an identifier"),
+    }
+}
+
+fn derives(traits: &[NestedMeta], name: &str) -> bool {
+    traits.iter().any(|attr| match attr {
+        NestedMeta::Meta(Meta::List(MetaList { path, nested, .. })) if path.is_ident("derive") => {
+            nested.iter().any(|derive| match derive {
+                NestedMeta::Meta(Meta::Path(path)) => path
+                    .segments
+                    .last()
+                    .is_some_and(|segment| segment.ident == name),
+                _ => false,
+            })
+        }
+        _ => false,
+    })
+}
+
+fn get_variant_attrs(variant: &Variant) -> Vec<NestedMeta> {
+    variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("enum_kind_attr"))
+        .flat_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(MetaList { nested, .. })) => nested,
+            _ => panic!("#[enum_kind_attr(ATTR)] requires ATTR to be a list of attributes"),
+        })
+        .collect()
+}
+
+fn get_variant_aliases(variant: &Variant) -> Vec<LitStr> {
+    variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("enum_kind_alias"))
+        .map(|attr| {
+            attr.parse_args()
+                .expect("#[enum_kind_alias(ALIAS)] requires ALIAS to be a string literal")
+        })
+        .collect()
+}
+
+fn default_derives(spec: &Specification) -> Vec<Ident> {
+    let mut derives = Vec::new();
+    if !spec.no_debug {
+        derives.push(format_ident!("Debug"));
+    }
+    derives.push(format_ident!("Clone"));
+    if !spec.no_copy {
+        derives.push(format_ident!("Copy"));
+    }
+    if !spec.eq_by_name {
+        derives.push(format_ident!("PartialEq"));
+        derives.push(format_ident!("Eq"));
+    }
+    derives
+}
+
+fn has_docs(traits: &[NestedMeta]) -> bool {
+    traits.iter().any(|attr| {
+        if let NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, .. })) = attr {
+            path.is_ident("doc")
+        } else {
+            false
+        }
+    })
+}
+
+fn create_kind_enum(definition: &DeriveInput, spec: &Specification) -> TokenStream {
+    let kind_ident = &spec.name;
+    // Stripping nothing still drops empty derive lists such as `derive()`.
+    let traits = &strip_derives(&spec.traits, &[]);
+    let deserialize = derives(traits, "Deserialize");
+    let catch_all_docs = format!(
+        "Any variant of [`{}`] marked with `#[enum_kind_skip]`.",
+        definition.ident
+    );
+    if let Some(default) = &spec.default_variant {
+        if !get_kind_variants(definition, spec)
+            .iter()
+            .any(|v| v.ident == *default)
+        {
+            panic!(
+                "#[enum_kind(NAME, default_variant = \"{}\")] does not name a variant of the kind enum",
+                default
+            );
+        }
+    }
+    let mut discriminants = kind_discriminants(definition, spec).into_iter();
+    let variants = get_kind_variants(definition, spec).into_iter().map(|v| {
+        let ident = &v.ident;
+        let discriminant = discriminants.next().flatten();
+        let default = if spec.default_variant.as_ref() == Some(ident) {
+            quote! { #[default] }
+        } else {
+            quote! {}
+        };
+        let v = match v.source {
+            Some(v) => v,
+            None => {
+                let serde_other = if deserialize {
+                    quote! { #[serde(other)] }
+                } else {
+                    quote! {}
+                };
+                return quote! { #[doc = #catch_all_docs] #serde_other #default #ident };
+            }
+        };
+        let forwarded = v.attrs.iter().filter(|attr| {
+            ["doc", "allow", "warn", "deny", "forbid", "expect"]
+                .iter()
+                .any(|name| attr.path.is_ident(name))
+        });
+        let attrs = get_variant_attrs(v);
+        let value = match discriminant {
+            Some(value) => quote! { = #value },
+            None => quote! {},
+        };
+        quote! { #( #forwarded )* #( #[#attrs] )* #default #ident #value }
+    });
+    let std = std_crate();
+    check_discriminants(definition, spec);
+    let kind_variants = get_kind_variants(definition, spec);
+    let doc_idents = kind_variants.iter().map(|v| &v.ident);
+    let doc_values = kind_variants
+        .iter()
+        .map(|v| match v.source.and_then(get_variant_docs) {
+            Some(docs) => quote! { #std::option::Option::Some(#docs) },
+            None => quote! { #std::option::Option::None },
+        });
+    let visibility = &definition.vis;
+    let docs_attr = if !has_docs(traits) && !spec.inherit_docs {
+        quote! {#[allow(missing_docs)]}
+    } else {
+        quote! {}
+    };
+    let inherited_docs = if spec.inherit_docs {
+        let summary = format!("Kind of [`{}`].", definition.ident);
+        let docs = definition
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("doc"));
+        quote! {
+            #[doc = #summary]
+            #[doc = ""]
+            #( #docs )*
+        }
+    } else {
+        quote! {}
+    };
+    let doc_alias = if spec.doc_alias {
+        let alias = definition.ident.to_string();
+        quote! {#[doc(alias = #alias)]}
+    } else {
+        quote! {}
+    };
+    let bound = &spec.bound;
+    let repr = match &spec.repr {
+        Some(repr) => quote! {#[repr(#repr)]},
+        None => quote! {},
+    };
+    let derives = default_derives(spec)
+        .into_iter()
+        .filter(|derive| !(spec.debug_with_value && derive == "Debug"));
+    let code = quote! {
+        #[derive(#(#derives),*)]
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #docs_attr
+        #inherited_docs
+        #doc_alias
+        #repr
+        #( #[#traits] )*
+        #visibility enum #kind_ident #bound {
+            #(#variants),*
+        }
+
+        impl #kind_ident {
+            /// Returns the documentation of the variant this kind was derived from.
+            pub const fn variant_docs(&self) -> #std::option::Option<&'static str> {
+                match *self {
+                    #( #kind_ident::#doc_idents => #doc_values, )*
+                }
+            }
+        }
+    };
+    code
+}
+
+fn check_discriminants(definition: &DeriveInput, spec: &Specification) {
+    // Only literal values, and the values Rust assigns after them, are known
+    // here. Anything else is left for the compiler to check.
+    let variants = get_kind_variants(definition, spec);
+    let discriminants = kind_discriminants(definition, spec);
+    let mut seen: Vec<(i128, &Ident)> = Vec::new();
+    let mut next = Some(0);
+    for (variant, discriminant) in variants.iter().zip(discriminants) {
+        let value = match discriminant {
+            Some(expr) => literal_discriminant(&expr),
+            None => next,
+        };
+        if let Some(value) = value {
+            if let Some((_, other)) = seen.iter().find(|(seen, _)| *seen == value) {
+                panic!(
+                    "#[derive(EnumKind)] assigns the discriminant {} to both {} and {}",
+                    value, other, variant.ident
+                );
+            }
+            seen.push((value, &variant.ident));
+        }
+        next = value.and_then(|value| value.checked_add(1));
+    }
+}
+
+/// Returns the explicit discriminant of every kind variant, in the order of
+/// `get_kind_variants`.
+fn kind_discriminants(definition: &DeriveInput, spec: &Specification) -> Vec<Option<Expr>> {
+    // Rust numbers an implicit variant after the one before it, so with a `repr`
+    // the first variant following skipped ones is given the value the original
+    // enum assigns to it, keeping the implicit discriminants of both enums equal.
+    let mut values = Vec::new();
+    let mut original = Some(0);
+    let mut after_skipped = false;
+    for variant in get_variants(definition).iter() {
+        let explicit = variant.discriminant.as_ref().map(|(_, value)| value);
+        let current = explicit.map_or(original, literal_discriminant);
+        original = current.and_then(|value: i128| value.checked_add(1));
+        if is_skipped(variant) {
+            after_skipped = true;
+            continue;
+        }
+        let copied = explicit.filter(|_| spec.repr.is_some()).cloned();
+        let value = match get_variant_value(variant).code.or(copied) {
+            Some(value) => Some(value),
+            None if after_skipped && spec.repr.is_some() => current.map(|value| {
+                let literal = proc_macro2::Literal::u128_unsuffixed(value.unsigned_abs());
+                if value < 0 {
+                    parse_quote!(-#literal)
+                } else {
+                    parse_quote!(#literal)
+                }
+            }),
+            None => None,
+        };
+        after_skipped = false;
+        values.push(value);
+    }
+    if spec.catch_all.is_some() {
+        values.push(None);
+    }
+    values
+}
+
+fn literal_discriminant(expr: &Expr) -> Option<i128> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(value),
+            ..
+        }) => value.base10_parse().ok(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => literal_discriminant(expr).map(|value| -value),
+        Expr::Paren(ExprParen { expr, .. }) => literal_discriminant(expr),
+        _ => None,
+    }
+}
+
+fn get_variant_docs(variant: &Variant) -> Option<String> {
+    let lines: Vec<String> = variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(MetaNameValue {
+                lit: Lit::Str(value),
+                ..
+            })) => Some(value.value()),
+            _ => None,
+        })
+        .map(|line| line.strip_prefix(' ').unwrap_or(&line).to_owned())
+        .collect();
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n"))
+    }
+}
+
+fn is_uninhabited_enum(definition: &DeriveInput) -> bool {
+    if let Data::Enum(ref data) = definition.data {
+        return data.variants.is_empty();
+    }
+    false
+}
+
+fn create_impl(definition: &DeriveInput, spec: &Specification) -> TokenStream {
+    let kind_ident = &spec.name;
+    let (base_impl_generics, ty_generics, where_clause) = definition.generics.split_for_impl();
+    let ident = &definition.ident;
+
+    let arms = match &definition.data {
+        &Data::Enum(DataEnum { ref variants, .. }) => variants.iter().map(|v| {
+            let variant = &v.ident;
+            let kind = match &spec.catch_all {
+                Some(catch_all) if is_skipped(v) => catch_all.clone(),
+                _ => kind_variant_ident(v),
+            };
+            match v.fields {
+                Fields::Unit => quote! {
+                    #ident::#variant => #kind_ident::#kind,
+                },
+                Fields::Unnamed(_) => quote! {
+                    #ident::#variant(..) => #kind_ident::#kind,
+                },
+                Fields::Named(_) => quote! {
+                    #ident::#variant{..} => #kind_ident::#kind,
+                },
+            }
+        }),
+        _ => {
+            panic!("#[derive(EnumKind)] is only allowed for enums");
+        }
+    };
+
+    let try_kind = create_try_kind(definition, spec);
+    if is_partial(definition, spec) {
+        if spec.inherent_only {
+            panic!("#[enum_kind(NAME, inherent_only)] requires every variant to have a kind, add a catch_all");
+        }
+        return try_kind;
+    }
+
+    let trait_: Path = if cfg!(feature = "no-stdlib") {
+        parse_quote!(::core::convert::From)
+    } else {
+        parse_quote!(::std::convert::From)
+    };
+
+    let used: HashSet<Lifetime> = definition
+        .generics
+        .lifetimes()
+        .map(|ld| ld.lifetime.clone())
+        .collect();
+    let (prefix, mut counter) = match &spec.lifetime {
+        Some(name) => (name.as_str(), None),
+        None => ("__enum_kinds", Some(1)),
+    };
+    let a = loop {
+        let name = match counter {
+            Some(counter) => format!("'{}{}", prefix, counter),
+            None => format!("'{}", prefix),
+        };
+        let lifetime: Lifetime = syn::parse_str(&name).unwrap();
+        if !used.contains(&lifetime) {
+            break LifetimeDef::new(lifetime);
+        }
+        counter = Some(counter.map_or(1, |counter| counter + 1));
+    };
+
+    // The synthetic lifetime needs no bounds of its own: `&'a Enum<...>` being
+    // well-formed already implies them, and the original where clause is reused
+    // verbatim so bounds such as `T: 'b` or `'b: 'c` are preserved.
+    let mut generics = definition.generics.clone();
+    generics.params.insert(0, GenericParam::Lifetime(a.clone()));
+    let (impl_generics, _, _) = generics.split_for_impl();
+
+    let impl_ = if is_uninhabited_enum(definition) {
+        quote! {
+            unreachable!();
+        }
+    } else {
+        quote! {
+            match _value {
+                #(#arms)*
+            }
+        }
+    };
+
+    if spec.inherent_only {
+        return quote! {
+            #[allow(unused_attributes)]
+            impl #base_impl_generics #ident#ty_generics #where_clause {
+                /// Returns the kind of this value.
+                pub fn kind(&self) -> #kind_ident {
+                    let _value = self;
+                    #impl_
+                }
+
+                /// Returns the kind of this value.
+                ///
+                /// This is the same as `kind`.
+                pub fn as_kind(&self) -> #kind_ident {
+                    self.kind()
+                }
+            }
+
+            #[automatically_derived]
+            #[allow(unused_attributes)]
+            impl #base_impl_generics ::enum_kinds::ToKind for #ident#ty_generics #where_clause {
+                type Kind = #kind_ident;
+
+                const KIND_COUNT: usize = #kind_ident::COUNT;
+
+                fn kind(&self) -> Self::Kind {
+                    <#ident#ty_generics>::kind(self)
+                }
+
+                fn kind_name(&self) -> &'static str {
+                    ::enum_kinds::ToKind::kind(self).name()
+                }
+            }
+
+            #[automatically_derived]
+            #[allow(unused_attributes)]
+            impl #base_impl_generics ::enum_kinds::IntoKind for #ident#ty_generics #where_clause {
+                fn into_kind(self) -> Self::Kind {
+                    ::enum_kinds::ToKind::kind(&self)
+                }
+            }
+
+            #try_kind
+        };
+    }
+
+    // Without the borrowed `From`, both the owned one and `ToKind` match on the
+    // value directly.
+    let (from, kind) = if spec.owned_only {
+        let from = quote! {
+            #[automatically_derived]
+            #[allow(unused_attributes)]
+            impl #base_impl_generics #trait_<#ident#ty_generics> for #kind_ident #where_clause {
+                fn from(_value: #ident#ty_generics) -> Self {
+                    #impl_
+                }
+            }
+        };
+        let kind = quote! {
+            let _value = self;
+            #impl_
+        };
+        (from, kind)
+    } else {
+        let from = quote! {
+            #[automatically_derived]
+            #[allow(unused_attributes)]
+            impl #impl_generics #trait_<&#a #ident#ty_generics> for #kind_ident #where_clause {
+                fn from(_value: &#a #ident#ty_generics) -> Self {
+                    #impl_
+                }
+            }
+
+            #[automatically_derived]
+            #[allow(unused_attributes)]
+            impl #impl_generics #trait_<#ident#ty_generics> for #kind_ident #where_clause {
+                fn from(value: #ident#ty_generics) -> Self {
+                    #kind_ident::from(&value)
+                }
+            }
+        };
+        (from, quote! { #kind_ident::from(self) })
+    };
+
+    let tokens = quote! {
+        #from
+
+        #[automatically_derived]
+        #[allow(unused_attributes)]
+        impl #base_impl_generics ::enum_kinds::ToKind for #ident#ty_generics #where_clause {
+            type Kind = #kind_ident;
+
+            const KIND_COUNT: usize = #kind_ident::COUNT;
+
+            fn kind(&self) -> Self::Kind {
+                #kind
+            }
+
+            fn kind_name(&self) -> &'static str {
+                ::enum_kinds::ToKind::kind(self).name()
+            }
+        }
+
+        #[automatically_derived]
+        #[allow(unused_attributes)]
+        impl #base_impl_generics ::enum_kinds::IntoKind for #ident#ty_generics #where_clause {
+            fn into_kind(self) -> Self::Kind {
+                ::enum_kinds::ToKind::kind(&self)
+            }
+        }
+
+        #try_kind
+    };
+    tokens
+}
+
+fn create_try_kind(definition: &DeriveInput, spec: &Specification) -> TokenStream {
+    if !spec.fallible {
+        return quote! {};
+    }
+    let ident = &definition.ident;
+    let kind_ident = &spec.name;
+    let (impl_generics, ty_generics, where_clause) = definition.generics.split_for_impl();
+    let std = std_crate();
+    let visibility = &definition.vis;
+    let error = format_ident!("Skipped{}Error", kind_name(kind_ident));
+    let error_message = format!("variant {{}} has no {}", kind_name(kind_ident));
+    let arms = get_variants(definition).iter().map(|v| {
+        let variant = &v.ident;
+        let result = match &spec.catch_all {
+            _ if !is_skipped(v) => {
+                let kind = kind_variant_ident(v);
+                quote! { #std::result::Result::Ok(#kind_ident::#kind) }
+            }
+            Some(catch_all) => quote! { #std::result::Result::Ok(#kind_ident::#catch_all) },
+            None => {
+                let name = variant.to_string();
+                quote! { #std::result::Result::Err(#error { variant: #name }) }
+            }
+        };
+        match v.fields {
+            Fields::Unit => quote! { #ident::#variant => #result, },
+            Fields::Unnamed(_) => quote! { #ident::#variant(..) => #result, },
+            Fields::Named(_) => quote! { #ident::#variant{..} => #result, },
+        }
+    });
+    let body = if is_uninhabited_enum(definition) {
+        quote! { unreachable!() }
+    } else {
+        quote! {
+            match self {
+                #(#arms)*
+            }
+        }
+    };
+    quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Returns the kind of this value, or an error for variants marked
+            /// with `#[enum_kind_skip]` when there is no catch-all variant.
+            pub fn try_kind(&self) -> #std::result::Result<#kind_ident, #error> {
+                #body
+            }
+        }
+
+        /// The error returned by `try_kind` for a variant without a kind.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #visibility struct #error {
+            variant: &'static str,
+        }
+
+        impl #error {
+            /// Returns the name of the variant that has no kind.
+            pub fn variant(&self) -> &'static str {
+                self.variant
+            }
+        }
+
+        #[automatically_derived]
+        impl #std::fmt::Display for #error {
+            fn fmt(&self, f: &mut #std::fmt::Formatter) -> #std::fmt::Result {
+                #std::write!(f, #error_message, self.variant)
+            }
+        }
+
+        #[automatically_derived]
+        impl #std::error::Error for #error {}
+    }
+}
+
+fn create_original_methods(definition: &DeriveInput, spec: &Specification) -> TokenStream {
+    let ident = &definition.ident;
+    let kind_ident = &spec.name;
+    let (impl_generics, ty_generics, where_clause) = definition.generics.split_for_impl();
+    let std = std_crate();
+    if spec.with_kind && is_partial(definition, spec) {
+        panic!(
+            "#[enum_kind(NAME, with_kind)] requires every variant to have a kind, add a catch_all"
+        );
+    }
+    let with_kind = if spec.with_kind {
+        quote! {
+            /// Returns the kind of this value together with the value itself.
+            pub fn with_kind(self) -> (#kind_ident, Self) {
+                (::enum_kinds::ToKind::kind(&self), self)
+            }
+        }
+    } else {
+        quote! {}
+    };
+    let std_discriminant = if spec.std_discriminant {
+        quote! {
+            /// Returns the `mem::discriminant` of this value.
+            ///
+            /// When no variant is skipped, two values have equal discriminants
+            /// exactly when they have the same kind, but unlike kinds,
+            /// discriminants can only be compared with each other and cannot be
+            /// named or enumerated. Skipped variants keep their own
+            /// discriminants even when they share the catch-all kind.
+            pub fn std_discriminant(&self) -> #std::mem::Discriminant<Self> {
+                #std::mem::discriminant(self)
+            }
+        }
+    } else {
+        quote! {}
+    };
+    quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #with_kind
+            #std_discriminant
+        }
+    }
+}
+
+fn create_char_conversions(definition: &DeriveInput, spec: &Specification) -> TokenStream {
+    let kind_ident = &spec.name;
+    let variants = get_kind_variants(definition, spec);
+    if variants
+        .iter()
+        .filter_map(|v| v.source)
+        .all(|source| get_variant_char(source).is_none())
+    {
+        return quote! {};
+    }
+    // The catch-all variant has no attributes of its own, so its character
+    // comes from `catch_all_char` instead.
+    let chars: Vec<(&Ident, Option<LitChar>)> = variants
+        .iter()
+        .map(|v| match v.source {
+            Some(source) => (&v.ident, get_variant_char(source)),
+            None => (&v.ident, spec.catch_all_char.clone()),
+        })
+        .collect();
+    let mut seen = HashSet::new();
+    for (variant, c) in chars.iter() {
+        match c {
+            Some(c) if !seen.insert(c.value()) => panic!(
+                "#[enum_kind_char({:?})] is used by more than one variant",
+                c.value()
+            ),
+            Some(_) => continue,
+            None if spec.catch_all.as_ref() == Some(*variant) => panic!(
+                "#[enum_kind(NAME, catch_all_char = CHAR)] must be specified to label the catch-all variant {}",
+                variant
+            ),
+            None => panic!(
+                "#[enum_kind_char(CHAR)] must be specified for every variant, including {}",
+                variant
+            ),
+        }
+    }
+
+    let std = std_crate();
+    let visibility = &definition.vis;
+    let error = format_ident!("TryFromChar{}Error", kind_name(kind_ident));
+    let error_message = format!("invalid character {{:?}} for {}", kind_name(kind_ident));
+    let (idents, chars): (Vec<_>, Vec<_>) = chars
+        .into_iter()
+        .map(|(ident, c)| (ident, c.unwrap()))
+        .unzip();
+
+    quote! {
+        impl #kind_ident {
+            /// Returns the character assigned to this kind with `#[enum_kind_char]`.
+            pub const fn to_char(&self) -> char {
+                match *self {
+                    #( #kind_ident::#idents => #chars, )*
+                }
+            }
+        }
+
+        /// The error returned when converting a character that is not assigned
+        /// to any kind.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #visibility struct #error {
+            value: char,
+        }
+
+        impl #error {
+            /// Returns the character that failed to convert.
+            pub fn value(&self) -> char {
+                self.value
+            }
+        }
+
+        #[automatically_derived]
+        impl #std::fmt::Display for #error {
+            fn fmt(&self, f: &mut #std::fmt::Formatter) -> #std::fmt::Result {
+                #std::write!(f, #error_message, self.value)
+            }
+        }
+
+        #[automatically_derived]
+        impl #std::error::Error for #error {}
+
+        #[automatically_derived]
+        impl #std::convert::TryFrom<char> for #kind_ident {
+            type Error = #error;
+
+            fn try_from(value: char) -> #std::result::Result<Self, Self::Error> {
+                match value {
+                    #( #chars => #std::result::Result::Ok(#kind_ident::#idents), )*
+                    value => #std::result::Result::Err(#error { value }),
+                }
+            }
+        }
+    }
+}
+
+fn create_index_conversions(definition: &DeriveInput, spec: &Specification) -> TokenStream {
+    let kind_ident = &spec.name;
+    let index_type = &spec.index_type;
+    let max = integer_type_max(index_type).unwrap_or_else(|| {
+        panic!(
+            "#[enum_kind(NAME, index_type = \"TYPE\")] requires TYPE to be an integer type, found {}",
+            index_type
+        )
+    });
+    let variants = get_kind_variants(definition, spec);
+    if variants.len() as u128 > max.saturating_add(1) {
+        panic!(
+            "#[enum_kind(NAME, index_type = \"{}\")] cannot index {} variants",
+            index_type,
+            variants.len()
+        );
+    }
+
+    let std = std_crate();
+    let visibility = &definition.vis;
+    let error = format_ident!("TryFrom{}Error", kind_name(kind_ident));
+    let error_message = format!("index {{}} is out of range for {}", kind_name(kind_ident));
+    let idents: Vec<&Ident> = variants.iter().map(|v| &v.ident).collect();
+    let indices: Vec<proc_macro2::Literal> = (0..variants.len())
+        .map(proc_macro2::Literal::usize_unsuffixed)
+        .collect();
+    let (self_, other) = receiver(spec);
+
+    quote! {
+        impl #kind_ident {
+            /// Returns the position of this kind in the declaration order of
+            /// the variants.
+            pub const fn as_index(&self) -> #index_type {
+                match *self {
+                    #( #kind_ident::#idents => #indices, )*
+                }
+            }
+
+            /// Returns the kind at `index` in declaration order, or `None` if
+            /// there is no such kind. Unlike `TryFrom`, this can be used in
+            /// constant expressions.
+            pub const fn from_index(index: usize) -> #std::option::Option<Self> {
+                match index {
+                    #( #indices => #std::option::Option::Some(#kind_ident::#idents), )*
+                    _ => #std::option::Option::None,
+                }
+            }
+
+            /// Returns the kind at `index` after checking it against `COUNT`,
+            /// or `None` if it is out of range.
+            ///
+            /// This is the entry point for indices from untrusted sources, such
+            /// as integers received over FFI.
+            pub const fn from_raw_index(index: usize) -> #std::option::Option<Self> {
+                if index < Self::COUNT {
+                    Self::from_index(index)
+                } else {
+                    #std::option::Option::None
+                }
+            }
+
+            /// Returns `true` if this kind is declared before `other`.
+            pub const fn before(#self_, other: #other) -> bool {
+                self.as_index() < other.as_index()
+            }
+
+            /// Returns `true` if this kind is declared after `other`.
+            pub const fn after(#self_, other: #other) -> bool {
+                self.as_index() > other.as_index()
+            }
+
+            /// Returns the kind declared after this one, or `None` for the last
+            /// kind.
+            pub const fn next(#self_) -> #std::option::Option<Self> {
+                Self::from_index(self.as_index() as usize + 1)
+            }
+
+            /// Returns the kind declared before this one, or `None` for the
+            /// first kind.
+            pub const fn previous(#self_) -> #std::option::Option<Self> {
+                match (self.as_index() as usize).checked_sub(1) {
+                    #std::option::Option::Some(index) => Self::from_index(index),
+                    #std::option::Option::None => #std::option::Option::None,
+                }
+            }
+
+            /// Returns `true` if this kind is the same as `other`. Unlike `==`,
+            /// this can be used in constant expressions.
+            pub const fn const_eq(&self, other: &Self) -> bool {
+                self.as_index() == other.as_index()
+            }
+        }
+
+        /// The error returned when converting an index that does not
+        /// correspond to any kind.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #visibility struct #error {
+            value: #index_type,
+        }
+
+        impl #error {
+            /// Returns the index that failed to convert.
+            pub fn value(&self) -> #index_type {
+                self.value
+            }
+        }
+
+        #[automatically_derived]
+        impl #std::fmt::Display for #error {
+            fn fmt(&self, f: &mut #std::fmt::Formatter) -> #std::fmt::Result {
+                #std::write!(f, #error_message, self.value)
+            }
+        }
+
+        #[automatically_derived]
+        impl #std::error::Error for #error {}
+
+        #[automatically_derived]
+        impl #std::convert::From<#kind_ident> for #index_type {
+            fn from(kind: #kind_ident) -> Self {
+                kind.as_index()
+            }
+        }
+
+        #[automatically_derived]
+        impl #std::convert::TryFrom<#index_type> for #kind_ident {
+            type Error = #error;
+
+            fn try_from(value: #index_type) -> #std::result::Result<Self, Self::Error> {
+                match value {
+                    #( #indices => #std::result::Result::Ok(#kind_ident::#idents), )*
+                    value => #std::result::Result::Err(#error { value }),
+                }
+            }
+        }
+    }
+}
+
+fn create_default_impl(definition: &DeriveInput, spec: &Specification) -> TokenStream {
+    if !spec.auto_default {
+        return quote! {};
+    }
+    let kind_ident = &spec.name;
+    let variant = match get_kind_variants(definition, spec).as_slice() {
+        [variant] => variant.ident.clone(),
+        _ => {
+            panic!("#[enum_kind(NAME, auto_default)] requires the enum to have exactly one variant")
+        }
+    };
+    let std = std_crate();
+    quote! {
+        #[automatically_derived]
+        impl #std::default::Default for #kind_ident {
+            fn default() -> Self {
+                #kind_ident::#variant
+            }
+        }
+    }
+}
+
+fn create_repr_methods(definition: &DeriveInput, spec: &Specification) -> TokenStream {
+    let repr = match &spec.repr {
+        Some(repr) => repr,
+        None => return quote! {},
+    };
+    let kind_ident = &spec.name;
+    let variants = get_kind_variants(definition, spec);
+    let idents: Vec<&Ident> = variants.iter().map(|v| &v.ident).collect();
+    let std = std_crate();
+    let visibility = &definition.vis;
+    let error = format_ident!("TryFromRepr{}Error", kind_name(kind_ident));
+    let error_message = format!(
+        "discriminant {{}} is not assigned to any {}",
+        kind_name(kind_ident)
+    );
+    let debug = if spec.debug_with_value {
+        quote! {
+            #[automatically_derived]
+            impl #std::fmt::Debug for #kind_ident {
+                fn fmt(&self, f: &mut #std::fmt::Formatter) -> #std::fmt::Result {
+                    #std::write!(f, "{}({})", self.name(), self.repr_value())
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+    // Discriminants are checked to be unique, so hashing them agrees with the
+    // derived `Eq` while staying stable when variants are reordered.
+    let hash = if spec.hash && !spec.eq_by_name && !derives(&spec.traits, "Hash") {
+        quote! {
+            #[automatically_derived]
+            impl #std::hash::Hash for #kind_ident {
+                fn hash<H: #std::hash::Hasher>(&self, state: &mut H) {
+                    #std::hash::Hash::hash(&self.repr_value(), state)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+    quote! {
+        impl #kind_ident {
+            /// Returns the discriminant of this kind as its `repr` type.
+            ///
+            /// Unlike `as_index`, which is the position of the variant in
+            /// declaration order, this is the value assigned to the variant,
+            /// so the two differ when discriminants are set explicitly.
+            pub const fn repr_value(&self) -> #repr {
+                match *self {
+                    #( #kind_ident::#idents => #kind_ident::#idents as #repr, )*
+                }
+            }
+
+            /// Returns the kind whose discriminant is `value`.
+            pub const fn try_from_repr(value: #repr) -> #std::result::Result<Self, #error> {
+                #(
+                    if value == #kind_ident::#idents as #repr {
+                        return #std::result::Result::Ok(#kind_ident::#idents);
+                    }
+                )*
+                #std::result::Result::Err(#error { value })
+            }
+        }
+
+        /// The error returned when converting a discriminant that is not
+        /// assigned to any kind.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #visibility struct #error {
+            value: #repr,
+        }
+
+        impl #error {
+            /// Returns the discriminant that failed to convert.
+            pub fn value(&self) -> #repr {
+                self.value
+            }
+        }
+
+        #[automatically_derived]
+        impl #std::fmt::Display for #error {
+            fn fmt(&self, f: &mut #std::fmt::Formatter) -> #std::fmt::Result {
+                #std::write!(f, #error_message, self.value)
+            }
+        }
+
+        #[automatically_derived]
+        impl #std::error::Error for #error {}
+
+        #hash
+        #debug
+    }
+}
+
+fn create_toggle(definition: &DeriveInput, spec: &Specification) -> TokenStream {
+    if !spec.toggle {
+        return quote! {};
+    }
+    let variants = get_kind_variants(definition, spec);
+    let (first, second) = match variants.as_slice() {
+        [first, second] => (&first.ident, &second.ident),
+        _ => {
+            panic!("#[enum_kind(NAME, toggle)] requires the kind enum to have exactly two variants")
+        }
+    };
+    let kind_ident = &spec.name;
+    let (self_, _) = receiver(spec);
+    let std = std_crate();
+    quote! {
+        impl #kind_ident {
+            /// Returns the other kind.
+            pub const fn toggle(#self_) -> Self {
+                match self {
+                    #kind_ident::#first => #kind_ident::#second,
+                    #kind_ident::#second => #kind_ident::#first,
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #std::ops::Not for #kind_ident {
+            type Output = Self;
+
+            fn not(self) -> Self {
+                self.toggle()
+            }
+        }
+    }
+}
+
+fn create_all_variants(definition: &DeriveInput, spec: &Specification) -> TokenStream {
+    let kind_ident = &spec.name;
+    let variants = get_kind_variants(definition, spec);
+    let idents: Vec<&Ident> = variants.iter().map(|v| &v.ident).collect();
+    let count = idents.len();
+    let std = std_crate();
+    let visibility = &definition.vis;
+    let iter = format_ident!("{}Iter", kind_name(kind_ident));
+    let self_test = if spec.self_test {
+        quote! {
+            impl #kind_ident {
+                /// Panics if `ALL`, `COUNT`, `iter` and `as_index` disagree
+                /// with each other.
+                pub fn __assert_kind_invariants() {
+                    #std::assert!(
+                        Self::ALL.len() == Self::COUNT,
+                        "ALL and COUNT disagree on the number of kinds"
+                    );
+                    let mut visited = 0;
+                    for (position, kind) in Self::iter().enumerate() {
+                        #std::assert!(
+                            kind.as_index() as usize == position,
+                            "iter visits kinds out of declaration order"
+                        );
+                        #std::assert!(
+                            Self::ALL[position] == kind,
+                            "iter and ALL disagree on the order of kinds"
+                        );
+                        visited += 1;
+                    }
+                    #std::assert!(visited == Self::COUNT, "iter does not visit every kind");
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+    let sets = if cfg!(feature = "no-stdlib") {
+        quote! {}
+    } else {
+        let btree_set = if derives(&spec.traits, "Ord") || spec.ord {
+            quote! {
+                /// Returns an ordered set containing every kind.
+                ///
+                /// Available when `Ord` is derived for the kind enum.
+                pub fn all_set() -> ::std::collections::BTreeSet<Self> {
+                    Self::ALL.iter().cloned().collect()
+                }
+            }
+        } else {
+            quote! {}
+        };
+        let hash_set = if spec.hash || derives(&spec.traits, "Hash") || spec.eq_by_name {
+            quote! {
+                /// Returns a hash set containing every kind.
+                ///
+                /// Available when the kind enum implements `Hash`.
+                pub fn all_hash_set() -> ::std::collections::HashSet<Self> {
+                    Self::ALL.iter().cloned().collect()
+                }
+            }
+        } else {
+            quote! {}
+        };
+        quote! {
+            #btree_set
+            #hash_set
+        }
+    };
+    let random = if cfg!(feature = "rand") {
+        quote! {
+            /// Returns a random kind other than `excluded`, or `None` if
+            /// `excluded` is the only kind.
+            pub fn random_except<R: ::enum_kinds::__rand::Rng + ?Sized>(
+                rng: &mut R,
+                excluded: Self,
+            ) -> #std::option::Option<Self> {
+                if Self::COUNT < 2 {
+                    return #std::option::Option::None;
+                }
+                let excluded = excluded.as_index() as usize;
+                let index = rng.gen_range(0..Self::COUNT - 1);
+                Self::at(if index < excluded { index } else { index + 1 })
+            }
+        }
+    } else {
+        quote! {}
+    };
+    // A catch-all variant keeps the kind inhabited even for an empty enum.
+    let uninhabited = count == 0;
+    quote! {
+        impl #kind_ident {
+            /// The number of kinds.
+            pub const COUNT: usize = #count;
+
+            /// Whether the kind enum has no variants, and thus no values.
+            pub const IS_UNINHABITED: bool = #uninhabited;
+
+            /// Every kind in declaration order.
+            pub const ALL: [Self; #count] = [#( #kind_ident::#idents ),*];
+
+            /// Returns the kind at `index` in declaration order, or `None` if
+            /// `index` is not less than `COUNT`.
+            pub fn at(index: usize) -> #std::option::Option<Self> {
+                Self::ALL.get(index).cloned()
+            }
+
+            /// Returns an iterator over every kind in declaration order.
+            pub fn iter() -> #iter {
+                #iter { index: 0 }
+            }
+
+            #sets
+            #random
+        }
+
+        /// An iterator over every kind in declaration order.
+        #[derive(Debug, Clone)]
+        #visibility struct #iter {
+            index: usize,
+        }
+
+        #[automatically_derived]
+        impl #std::iter::Iterator for #iter {
+            type Item = #kind_ident;
+
+            fn next(&mut self) -> #std::option::Option<Self::Item> {
+                let kind = #kind_ident::ALL.get(self.index).cloned();
+                if kind.is_some() {
+                    self.index += 1;
+                }
+                kind
+            }
+
+            fn size_hint(&self) -> (usize, #std::option::Option<usize>) {
+                let remaining = #kind_ident::COUNT - self.index;
+                (remaining, #std::option::Option::Some(remaining))
+            }
+        }
+
+        #[automatically_derived]
+        impl #std::iter::ExactSizeIterator for #iter {}
+
+        #self_test
+    }
+}
+
+fn create_name_methods(definition: &DeriveInput, spec: &Specification) -> TokenStream {
+    let kind_ident = &spec.name;
+    let variants = get_kind_variants(definition, spec);
+    let idents: Vec<&Ident> = variants.iter().map(|v| &v.ident).collect();
+    let names: Vec<String> = variants.iter().map(|v| variant_name(v, spec)).collect();
+    let count = idents.len();
+    let indices = 0..count;
+    let mut seen = HashSet::new();
+    for name in names.iter() {
+        if !seen.insert(name.clone()) {
+            panic!(
+                "#[derive(EnumKind)] found more than one variant named {:?}",
+                name
+            );
+        }
+    }
+    let aliases: Vec<Vec<LitStr>> = variants
+        .iter()
+        .map(|v| match v.source {
+            Some(source) => {
+                let mut aliases = get_variant_aliases(source);
+                aliases.extend(get_variant_value(source).aliases);
+                aliases
+            }
+            None => Vec::new(),
+        })
+        .collect();
+    for alias in aliases.iter().flatten() {
+        if !seen.insert(alias.value()) {
+            panic!(
+                "#[enum_kind_alias({:?})] conflicts with another name or alias",
+                alias.value()
+            );
+        }
+    }
+
+    let std = std_crate();
+    let visibility = &definition.vis;
+    let error = format_ident!("Parse{}Error", kind_name(kind_ident));
+    let error_message = format!("invalid {} name", kind_name(kind_ident));
+
+    let json = if cfg!(feature = "serde_json") && !cfg!(feature = "no-stdlib") {
+        let json_names = names.iter().map(|name| json_string(name));
+        quote! {
+            impl #kind_ident {
+                /// Returns the name of this kind as a JSON string.
+                pub fn to_json_string(&self) -> ::std::string::String {
+                    match *self {
+                        #( #kind_ident::#idents => ::std::string::String::from(#json_names), )*
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let owned = if cfg!(feature = "no-stdlib") {
+        quote! {}
+    } else {
+        quote! {
+            #[automatically_derived]
+            impl ::std::convert::From<#kind_ident> for ::std::string::String {
+                fn from(kind: #kind_ident) -> Self {
+                    ::std::borrow::ToOwned::to_owned(kind.name())
+                }
+            }
+        }
+    };
+    let display = if spec.display {
+        quote! {
+            #[automatically_derived]
+            impl #std::fmt::Display for #kind_ident {
+                fn fmt(&self, f: &mut #std::fmt::Formatter) -> #std::fmt::Result {
+                    f.write_str(self.name())
+                }
+            }
+
+            #[automatically_derived]
+            impl #std::convert::AsRef<str> for #kind_ident {
+                fn as_ref(&self) -> &str {
+                    self.name()
+                }
+            }
+
+            #[automatically_derived]
+            impl #std::convert::From<#kind_ident> for &'static str {
+                fn from(kind: #kind_ident) -> Self {
+                    kind.name()
+                }
+            }
+
+            #owned
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        impl #kind_ident {
+            /// The names of every kind in declaration order.
+            pub const VARIANT_NAMES: [&'static str; #count] = [#( #names ),*];
+
+            /// The index and name of every kind in declaration order.
+            pub const INDEXED_NAMES: &'static [(usize, &'static str)] =
+                &[#( (#indices, #names) ),*];
+
+            /// Returns the name of this kind.
+            pub const fn name(&self) -> &'static str {
+                match *self {
+                    #( #kind_ident::#idents => #names, )*
+                }
+            }
+
+            /// Returns the kind with the given name, if there is one.
+            pub fn from_name(name: &str) -> #std::option::Option<Self> {
+                match name {
+                    #( #names #(| #aliases)* => #std::option::Option::Some(#kind_ident::#idents), )*
+                    _ => #std::option::Option::None,
+                }
+            }
+        }
+
+        /// The error returned when parsing a string that is not the name of
+        /// any kind.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #visibility struct #error {
+            _private: (),
+        }
+
+        #[automatically_derived]
+        impl #std::fmt::Display for #error {
+            fn fmt(&self, f: &mut #std::fmt::Formatter) -> #std::fmt::Result {
+                f.write_str(#error_message)
+            }
+        }
+
+        #[automatically_derived]
+        impl #std::error::Error for #error {}
+
+        #[automatically_derived]
+        impl #std::str::FromStr for #kind_ident {
+            type Err = #error;
+
+            fn from_str(s: &str) -> #std::result::Result<Self, Self::Err> {
+                #kind_ident::from_name(s).ok_or(#error { _private: () })
+            }
+        }
+
+        #display
+        #json
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut json = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn create_kind_map(definition: &DeriveInput, spec: &Specification) -> TokenStream {
+    if !spec.map {
+        return quote! {};
+    }
+    let kind_ident = &spec.name;
+    let std = std_crate();
+    let visibility = &definition.vis;
+    let map = format_ident!("{}Map", kind_name(kind_ident));
+    let kind_ty = if spec.no_copy {
+        quote! {&#kind_ident}
+    } else {
+        quote! {#kind_ident}
+    };
+    quote! {
+        /// A map holding exactly one value for every kind.
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #visibility struct #map<T> {
+            values: [T; #kind_ident::COUNT],
+        }
+
+        impl<T> #map<T> {
+            /// Creates a map by calling `f` for every kind in declaration order.
+            pub fn from_fn<F: #std::ops::FnMut(#kind_ident) -> T>(mut f: F) -> Self {
+                #map {
+                    values: #std::array::from_fn(|index| f(#kind_ident::ALL[index].clone())),
+                }
+            }
+
+            /// Returns the value associated with `kind`.
+            pub fn get(&self, kind: #kind_ty) -> &T {
+                &self.values[kind.as_index() as usize]
+            }
+
+            /// Returns a mutable reference to the value associated with `kind`.
+            pub fn get_mut(&mut self, kind: #kind_ty) -> &mut T {
+                &mut self.values[kind.as_index() as usize]
+            }
+        }
+    }
+}
+
+fn create_kind_array(definition: &DeriveInput, spec: &Specification) -> TokenStream {
+    if !spec.array {
+        return quote! {};
+    }
+    let kind_ident = &spec.name;
+    let std = std_crate();
+    let visibility = &definition.vis;
+    let array = format_ident!("{}Array", kind_name(kind_ident));
+    quote! {
+        /// An array with one element for every kind, indexed by kind.
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #visibility struct #array<T>(pub [T; #kind_ident::COUNT]);
+
+        impl<T> #array<T> {
+            /// Creates an array by calling `f` for every kind in declaration order.
+            pub fn from_fn<F: #std::ops::FnMut(#kind_ident) -> T>(mut f: F) -> Self {
+                #array(#std::array::from_fn(|index| f(#kind_ident::ALL[index].clone())))
+            }
+        }
+
+        #[automatically_derived]
+        impl<T> #std::ops::Index<#kind_ident> for #array<T> {
+            type Output = T;
+
+            fn index(&self, kind: #kind_ident) -> &T {
+                &self.0[kind.as_index() as usize]
+            }
+        }
+
+        #[automatically_derived]
+        impl<T> #std::ops::IndexMut<#kind_ident> for #array<T> {
+            fn index_mut(&mut self, kind: #kind_ident) -> &mut T {
+                &mut self.0[kind.as_index() as usize]
+            }
+        }
+    }
+}
+
+fn create_kind_set(definition: &DeriveInput, spec: &Specification) -> TokenStream {
+    if !spec.set {
+        return quote! {};
+    }
+    let count = get_kind_variants(definition, spec).len();
+    if count > 128 {
+        panic!(
+            "#[enum_kind(NAME, set)] supports at most 128 variants, found {}",
+            count
+        );
+    }
+    let kind_ident = &spec.name;
+    let std = std_crate();
+    let visibility = &definition.vis;
+    let set = format_ident!("{}Set", kind_name(kind_ident));
+    let iter = format_ident!("{}SetIter", kind_name(kind_ident));
+    let (kind_ty, kind_arg) = if spec.no_copy {
+        (quote! {&#kind_ident}, quote! {&kind})
+    } else {
+        (quote! {#kind_ident}, quote! {kind})
+    };
+    let debug = if spec.no_debug {
+        quote! {}
+    } else {
+        quote! {
+            #[automatically_derived]
+            impl #std::fmt::Debug for #set {
+                fn fmt(&self, f: &mut #std::fmt::Formatter) -> #std::fmt::Result {
+                    f.debug_set().entries(self.iter()).finish()
+                }
+            }
+        }
+    };
+    quote! {
+        /// A set of kinds stored as a bitset.
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+        #visibility struct #set {
+            bits: u128,
+        }
+
+        impl #set {
+            /// Returns a set without any kinds.
+            pub const fn new() -> Self {
+                #set { bits: 0 }
+            }
+
+            const fn bit(kind: #kind_ty) -> u128 {
+                1 << kind.as_index() as u32
+            }
+
+            /// Adds `kind` to the set, returning `true` if it was not already present.
+            pub fn insert(&mut self, kind: #kind_ty) -> bool {
+                let present = self.contains(kind);
+                self.bits |= Self::bit(kind);
+                !present
+            }
+
+            /// Removes `kind` from the set, returning `true` if it was present.
+            pub fn remove(&mut self, kind: #kind_ty) -> bool {
+                let present = self.contains(kind);
+                self.bits &= !Self::bit(kind);
+                present
+            }
+
+            /// Returns `true` if the set contains `kind`.
+            pub const fn contains(&self, kind: #kind_ty) -> bool {
+                self.bits & Self::bit(kind) != 0
+            }
+
+            /// Returns the number of kinds in the set.
+            pub const fn len(&self) -> usize {
+                self.bits.count_ones() as usize
+            }
+
+            /// Returns `true` if the set contains no kinds.
+            pub const fn is_empty(&self) -> bool {
+                self.bits == 0
+            }
+
+            /// Returns an iterator over the kinds in the set in declaration order.
+            pub fn iter(&self) -> #iter {
+                #iter { bits: self.bits }
+            }
+        }
+
+        impl #kind_ident {
+            /// Returns a set containing `kinds`. This is what the `kind_set!`
+            /// macro expands to.
+            pub const fn set_of(kinds: &[#kind_ident]) -> #set {
+                let mut bits = 0;
+                let mut index = 0;
+                while index < kinds.len() {
+                    bits |= 1 << kinds[index].as_index() as u32;
+                    index += 1;
+                }
+                #set { bits }
+            }
+        }
+
+        #debug
+
+        /// An iterator over the kinds in a set in declaration order.
+        #[derive(Debug, Clone)]
+        #visibility struct #iter {
+            bits: u128,
+        }
+
+        #[automatically_derived]
+        impl #std::iter::Iterator for #iter {
+            type Item = #kind_ident;
+
+            fn next(&mut self) -> #std::option::Option<Self::Item> {
+                if self.bits == 0 {
+                    return #std::option::Option::None;
+                }
+                let index = self.bits.trailing_zeros() as usize;
+                self.bits &= self.bits - 1;
+                #kind_ident::from_index(index)
+            }
+
+            fn size_hint(&self) -> (usize, #std::option::Option<usize>) {
+                let remaining = self.bits.count_ones() as usize;
+                (remaining, #std::option::Option::Some(remaining))
+            }
+        }
+
+        #[automatically_derived]
+        impl #std::iter::ExactSizeIterator for #iter {}
+
+        #[automatically_derived]
+        impl #std::iter::FromIterator<#kind_ident> for #set {
+            fn from_iter<I: #std::iter::IntoIterator<Item = #kind_ident>>(iter: I) -> Self {
+                iter.into_iter().fold(#set::new(), |mut set, kind| {
+                    set.insert(#kind_arg);
+                    set
+                })
+            }
+        }
+
+        #[automatically_derived]
+        impl #std::iter::IntoIterator for #set {
+            type Item = #kind_ident;
+            type IntoIter = #iter;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        #[automatically_derived]
+        impl<'a> #std::iter::IntoIterator for &'a #set {
+            type Item = #kind_ident;
+            type IntoIter = #iter;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+    }
+}
+
+fn create_eq_by_name(spec: &Specification) -> TokenStream {
+    if !spec.eq_by_name {
+        return quote! {};
+    }
+    let kind_ident = &spec.name;
+    let std = std_crate();
+    quote! {
+        #[automatically_derived]
+        impl #std::cmp::PartialEq for #kind_ident {
+            fn eq(&self, other: &Self) -> bool {
+                self.name() == other.name()
+            }
+        }
+
+        #[automatically_derived]
+        impl #std::cmp::Eq for #kind_ident {}
+
+        #[automatically_derived]
+        impl #std::hash::Hash for #kind_ident {
+            fn hash<H: #std::hash::Hasher>(&self, state: &mut H) {
+                #std::hash::Hash::hash(self.name(), state)
+            }
+        }
+    }
+}
+
+fn create_conversions(spec: &Specification) -> TokenStream {
+    if !spec.conversions {
+        return quote! {};
+    }
+    let kind_ident = &spec.name;
+    let std = std_crate();
+    quote! {
+        #[automatically_derived]
+        impl #std::convert::AsRef<#kind_ident> for #kind_ident {
+            fn as_ref(&self) -> &#kind_ident {
+                self
+            }
+        }
+
+        #[automatically_derived]
+        impl<'a> #std::cmp::PartialEq<&'a #kind_ident> for #kind_ident {
+            fn eq(&self, other: &&'a #kind_ident) -> bool {
+                #std::cmp::PartialEq::eq(self, *other)
+            }
+        }
+
+        #[automatically_derived]
+        impl<'a> #std::cmp::PartialEq<#kind_ident> for &'a #kind_ident {
+            fn eq(&self, other: &#kind_ident) -> bool {
+                #std::cmp::PartialEq::eq(*self, other)
+            }
+        }
+    }
+}
+
+fn create_valuable(definition: &DeriveInput, spec: &Specification) -> TokenStream {
+    if !spec.valuable {
+        return quote! {};
+    }
+    if !cfg!(feature = "valuable") {
+        panic!("#[enum_kind(NAME, valuable)] requires the valuable feature of enum-kinds to be enabled");
+    }
+    let kind_ident = &spec.name;
+    let name = kind_name(kind_ident).to_string();
+    let idents: Vec<Ident> = get_kind_variants(definition, spec)
+        .into_iter()
+        .map(|v| v.ident)
+        .collect();
+    let names = idents.iter().map(|ident| ident.to_string());
+    let indices = 0..idents.len();
+    let valuable = quote! { ::enum_kinds::__valuable };
+    // Kinds are recorded as an enum of unit variants, which `valuable`
+    // describes as variants with zero unnamed fields.
+    quote! {
+        const _: () = {
+            static VARIANTS: &[#valuable::VariantDef<'static>] = &[
+                #( #valuable::VariantDef::new(#names, #valuable::Fields::Unnamed(0)), )*
+            ];
+
+            #[automatically_derived]
+            impl #valuable::Valuable for #kind_ident {
+                fn as_value(&self) -> #valuable::Value<'_> {
+                    #valuable::Value::Enumerable(self)
+                }
+
+                fn visit(&self, visit: &mut dyn #valuable::Visit) {
+                    visit.visit_unnamed_fields(&[]);
+                }
+            }
+
+            #[automatically_derived]
+            impl #valuable::Enumerable for #kind_ident {
+                fn definition(&self) -> #valuable::EnumDef<'_> {
+                    #valuable::EnumDef::new_static(#name, VARIANTS)
+                }
+
+                fn variant(&self) -> #valuable::Variant<'_> {
+                    match self {
+                        #( #kind_ident::#idents => #valuable::Variant::Static(&VARIANTS[#indices]), )*
+                    }
+                }
+            }
+        };
+    }
+}
+
+fn create_serde_as_index(spec: &Specification) -> TokenStream {
+    if !spec.serde_as_index {
+        return quote! {};
+    }
+    let kind_ident = &spec.name;
+    let serde = serde_path(spec);
+    let std = std_crate();
+    let expected = format!("an index of {}", kind_name(kind_ident));
+    quote! {
+        #[automatically_derived]
+        impl #serde::Serialize for #kind_ident {
+            fn serialize<S: #serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> #std::result::Result<S::Ok, S::Error> {
+                serializer.serialize_u64(self.as_index() as u64)
+            }
+        }
+
+        #[automatically_derived]
+        impl<'de> #serde::Deserialize<'de> for #kind_ident {
+            fn deserialize<D: #serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> #std::result::Result<Self, D::Error> {
+                let index = <u64 as #serde::Deserialize<'de>>::deserialize(deserializer)?;
+                <usize as #std::convert::TryFrom<u64>>::try_from(index)
+                    .ok()
+                    .and_then(#kind_ident::from_index)
+                    .ok_or_else(|| {
+                        <D::Error as #serde::de::Error>::invalid_value(
+                            #serde::de::Unexpected::Unsigned(index),
+                            &#expected,
+                        )
+                    })
+            }
+        }
+    }
+}
+
+fn create_ord(spec: &Specification) -> TokenStream {
+    if !spec.ord {
+        return quote! {};
+    }
+    let kind_ident = &spec.name;
+    let std = std_crate();
+    quote! {
+        #[automatically_derived]
+        impl #std::cmp::PartialOrd for #kind_ident {
+            fn partial_cmp(&self, other: &Self) -> #std::option::Option<#std::cmp::Ordering> {
+                #std::option::Option::Some(#std::cmp::Ord::cmp(self, other))
+            }
+        }
+
+        #[automatically_derived]
+        impl #std::cmp::Ord for #kind_ident {
+            fn cmp(&self, other: &Self) -> #std::cmp::Ordering {
+                #std::cmp::Ord::cmp(&self.as_index(), &other.as_index())
+            }
+        }
+    }
+}
+
+fn create_original_by_kind(definition: &DeriveInput, spec: &Specification) -> TokenStream {
+    if !spec.hash_original_by_kind {
+        return quote! {};
+    }
+    if is_partial(definition, spec) {
+        panic!("#[enum_kind(NAME, hash_original_by_kind)] requires every variant to have a kind, add a catch_all");
+    }
+    let ident = &definition.ident;
+    let (impl_generics, ty_generics, where_clause) = definition.generics.split_for_impl();
+    let std = std_crate();
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics #std::cmp::PartialEq for #ident #ty_generics #where_clause {
+            fn eq(&self, other: &Self) -> bool {
+                ::enum_kinds::ToKind::kind(self).as_index()
+                    == ::enum_kinds::ToKind::kind(other).as_index()
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #std::cmp::Eq for #ident #ty_generics #where_clause {}
+
+        #[automatically_derived]
+        impl #impl_generics #std::hash::Hash for #ident #ty_generics #where_clause {
+            fn hash<H: #std::hash::Hasher>(&self, state: &mut H) {
+                #std::hash::Hash::hash(&::enum_kinds::ToKind::kind(self).as_index(), state)
+            }
+        }
+    }
+}
+
+fn create_group_kind(definition: &DeriveInput, spec: &Specification) -> TokenStream {
+    let group_ident = match &spec.group_kind {
+        Some(group_ident) => group_ident,
+        None => return quote! {},
+    };
+    let kind_ident = &spec.name;
+    let variants = get_kind_variants(definition, spec);
+    let mut groups: Vec<Ident> = Vec::new();
+    let (idents, targets): (Vec<&Ident>, Vec<Ident>) = variants
+        .iter()
+        .map(|v| {
+            let group = match v.source {
+                Some(source) => get_variant_group(source).unwrap_or_else(|| {
+                    panic!(
+                        "#[enum_kind(NAME, group_kind = \"{}\")] requires every variant to have an #[enum_kind_group(GROUP)], including {}",
+                        group_ident, v.ident
+                    )
+                }),
+                None => v.ident.clone(),
+            };
+            if !groups.contains(&group) {
+                groups.push(group.clone());
+            }
+            (&v.ident, group)
+        })
+        .unzip();
+
+    let std = std_crate();
+    let visibility = &definition.vis;
+    // Unlike the kind enum, the group enum has no hand-written equality, so
+    // it derives `PartialEq` and `Eq` even with `eq_by_name`.
+    let mut derives = Vec::new();
+    if !spec.no_debug {
+        derives.push(format_ident!("Debug"));
+    }
+    derives.push(format_ident!("Clone"));
+    if !spec.no_copy {
+        derives.push(format_ident!("Copy"));
+    }
+    derives.push(format_ident!("PartialEq"));
+    derives.push(format_ident!("Eq"));
+    let summary = format!("Groups of [`{}`] variants.", kind_name(kind_ident));
+    quote! {
+        #[doc = #summary]
+        #[derive(#(#derives),*)]
+        #[allow(dead_code)]
+        #[allow(missing_docs)]
+        #visibility enum #group_ident {
+            #(#groups),*
+        }
+
+        #[automatically_derived]
+        impl #std::convert::From<#kind_ident> for #group_ident {
+            fn from(kind: #kind_ident) -> Self {
+                match kind {
+                    #( #kind_ident::#idents => #group_ident::#targets, )*
+                }
+            }
+        }
+    }
+}
+
+fn create_in_group(definition: &DeriveInput, spec: &Specification) -> TokenStream {
+    let variants = get_kind_variants(definition, spec);
+    let groups: Vec<Option<Ident>> = variants
+        .iter()
+        .map(|v| match v.source {
+            Some(source) => get_variant_group(source),
+            // As with `group_kind`, the catch-all is a group of its own.
+            None => Some(v.ident.clone()),
+        })
+        .collect();
+    if !variants
+        .iter()
+        .filter_map(|v| v.source)
+        .any(|source| get_variant_group(source).is_some())
+    {
+        return quote! {};
+    }
+    let kind_ident = &spec.name;
+    let idents = variants.iter().map(|v| &v.ident);
+    let matches = groups.iter().map(|group| match group {
+        Some(group) => {
+            let name = group.to_string();
+            quote! { group == #name }
+        }
+        None => quote! { false },
+    });
+    let std = std_crate();
+    quote! {
+        impl #kind_ident {
+            /// Returns an iterator over the kinds in `group`, as given by
+            /// `#[enum_kind_group(GROUP)]`, in declaration order.
+            pub fn in_group(group: &str) -> impl #std::iter::Iterator<Item = Self> + '_ {
+                Self::iter().filter(move |kind| match kind {
+                    #( #kind_ident::#idents => #matches, )*
+                })
+            }
+        }
+    }
+}
+
+fn create_predicates(definition: &DeriveInput, spec: &Specification) -> TokenStream {
+    if !spec.predicates {
+        return quote! {};
+    }
+    let kind_ident = &spec.name;
+    let variants = get_kind_variants(definition, spec);
+    // None of the other methods of the kind enum start with `is_`, so only
+    // variants whose names differ just in case or underscores can collide.
+    let mut seen: Vec<(String, &Ident)> = Vec::new();
+    for variant in variants.iter() {
+        let name = format!(
+            "is_{}",
+            NameStyle::Snake.apply(&variant.ident.unraw().to_string())
+        );
+        if let Some((_, other)) = seen.iter().find(|(seen, _)| *seen == name) {
+            panic!(
+                "#[enum_kind(NAME, predicates)] would generate {} for both {} and {}",
+                name, other, variant.ident
+            );
+        }
+        seen.push((name, &variant.ident));
+    }
+    let std = std_crate();
+    let methods = seen.iter().map(|(name, ident)| {
+        let method = format_ident!("{}", name);
+        let docs = format!("Returns `true` if this kind is `{}`.", ident);
+        quote! {
+            #[doc = #docs]
+            pub const fn #method(&self) -> bool {
+                #std::matches!(self, #kind_ident::#ident)
+            }
+        }
+    });
+    quote! {
+        impl #kind_ident {
+            #(#methods)*
+        }
+    }
+}
@@ -44,15 +44,15 @@
 #[macro_use]
 extern crate quote;
 extern crate proc_macro;
+extern crate proc_macro2;
 extern crate syn;
 
-use proc_macro::TokenStream;
-use quote::Tokens;
+use proc_macro2::TokenStream;
 use syn::{DeriveInput, Meta, NestedMeta, Ident, Data, MetaList, DataEnum, Fields};
 use syn::punctuated::Pair;
 
 #[proc_macro_derive(EnumKind, attributes(enum_kind_name))]
-pub fn enum_kind(input: TokenStream) -> TokenStream {
+pub fn enum_kind(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = syn::parse(input).expect("#[derive(EnumKind)] failed to parse input");
     let name = get_enum_name(&ast)
         .expect("#[derive(EnumKind)] requires an associated #[enum_kind_name(NAME)] to be specified");
@@ -67,24 +67,25 @@ pub fn enum_kind(input: TokenStream) -> TokenStream {
 
 fn get_enum_name(definition: &DeriveInput) -> Option<Ident> {
     for attr in definition.attrs.iter() {
-        match attr.interpret_meta() {
-            Some(Meta::List(MetaList { ident, ref nested, .. })) if ident == "enum_kind_name" => {
-                if let Some(Pair::End(&NestedMeta::Meta(Meta::Word(ident)))) = nested.pairs().next() {
-                    return Some(ident.clone());
-                } else {
-                    panic!("#[enum_kind_name(NAME)] requires an identifier NAME to be specified");
+        match attr.parse_meta() {
+            Ok(Meta::List(MetaList { ref path, ref nested, .. })) if path.is_ident("enum_kind_name") => {
+                if let Some(Pair::End(&NestedMeta::Meta(Meta::Path(ref path)))) = nested.pairs().next() {
+                    if let Some(ident) = path.get_ident() {
+                        return Some(ident.clone());
+                    }
                 }
+                panic!("#[enum_kind_name(NAME)] requires an identifier NAME to be specified");
             },
             _ => continue
         }
     }
-    return None;
+    None
 }
 
-fn create_kind_enum(definition: &DeriveInput, kind_ident: &Ident) -> Tokens {
+fn create_kind_enum(definition: &DeriveInput, kind_ident: &Ident) -> TokenStream {
     let variant_idents = match &definition.data {
         &Data::Enum(DataEnum { ref variants, .. }) => {
-            variants.iter().map(|ref v| v.ident.clone())
+            variants.iter().map(|v| &v.ident)
         }
         _ => {
             panic!("#[derive(EnumKind)] is only allowed for enums");
@@ -101,13 +102,13 @@ fn create_kind_enum(definition: &DeriveInput, kind_ident: &Ident) -> Tokens {
     }
 }
 
-fn create_impl(definition: &DeriveInput, kind_ident: &Ident) -> Tokens {
+fn create_impl(definition: &DeriveInput, kind_ident: &Ident) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = definition.generics.split_for_impl();
     let ident = &definition.ident;
 
     let arms = match &definition.data {
         &Data::Enum(DataEnum { ref variants, .. }) => {
-            variants.iter().map(|ref v| {
+            variants.iter().map(|v| {
                 let variant = &v.ident;
                 match v.fields {
                     Fields::Unit => quote! {
@@ -40,3 +40,23 @@ fn test_named() {
     };
     assert_eq!(foo.kind(), NamedEnumKind::Foo);
 }
+
+#[derive(EnumKind)]
+#[enum_kind_name(GenericEnumKind)]
+#[allow(dead_code)]
+enum GenericEnum<'a, T: 'a> {
+    Value(T),
+    Borrowed {
+        value: &'a T
+    },
+    Nothing
+}
+
+#[test]
+fn test_generic() {
+    let value = 32;
+    let borrowed: GenericEnum<u32> = GenericEnum::Borrowed { value: &value };
+    assert_eq!(borrowed.kind(), GenericEnumKind::Borrowed);
+    assert_eq!(GenericEnum::Value("Example").kind(), GenericEnumKind::Value);
+    assert_eq!(GenericEnum::Nothing::<u8>.kind(), GenericEnumKind::Nothing);
+}
@@ -1,183 +1,114 @@
 #![doc = include_str!("../README.md")]
+#![no_std]
 
-extern crate proc_macro;
-extern crate proc_macro2;
-extern crate quote;
-#[macro_use]
-extern crate syn;
-
-use proc_macro2::TokenStream;
-use quote::quote;
-use std::collections::HashSet;
-use syn::punctuated::Punctuated;
-use syn::{
-    Data, DataEnum, DeriveInput, Fields, GenericParam, Lifetime, LifetimeDef, Meta, MetaList,
-    MetaNameValue, NestedMeta, Path,
-};
-
-#[proc_macro_derive(EnumKind, attributes(enum_kind))]
-pub fn enum_kind(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let ast = syn::parse(input).expect("#[derive(EnumKind)] failed to parse input");
-    let (name, traits) = get_enum_specification(&ast);
-    let enum_ = create_kind_enum(&ast, &name, traits);
-    let impl_ = create_impl(&ast, &name);
-    let code = quote! {
-        #enum_
-        #impl_
-    };
-    proc_macro::TokenStream::from(code)
+pub use enum_kinds_derive::EnumKind;
+
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+pub use rand as __rand;
+
+#[cfg(feature = "valuable")]
+#[doc(hidden)]
+pub use valuable as __valuable;
+
+/// Types that have an associated kind enum.
+///
+/// Implemented automatically by `#[derive(EnumKind)]`.
+pub trait ToKind {
+    /// The generated kind enum.
+    type Kind;
+
+    /// The number of variants of the kind enum.
+    const KIND_COUNT: usize;
+
+    /// Returns the kind of this value.
+    fn kind(&self) -> Self::Kind;
+
+    /// Returns the name of the kind of this value.
+    fn kind_name(&self) -> &'static str;
 }
 
-fn find_attribute(
-    definition: &DeriveInput,
-    name: &str,
-) -> Option<Punctuated<NestedMeta, syn::token::Comma>> {
-    for attr in definition.attrs.iter() {
-        match attr.parse_meta() {
-            Ok(Meta::List(MetaList {
-                ref path,
-                ref nested,
-                ..
-            })) if path.is_ident(name) => return Some(nested.clone()),
-            _ => continue,
-        }
+impl<T: ToKind + ?Sized> ToKind for &T {
+    type Kind = T::Kind;
+
+    const KIND_COUNT: usize = T::KIND_COUNT;
+
+    fn kind(&self) -> Self::Kind {
+        (**self).kind()
     }
-    None
-}
 
-fn get_enum_specification(definition: &DeriveInput) -> (Path, Vec<NestedMeta>) {
-    let params = find_attribute(definition, "enum_kind")
-        .expect("#[derive(EnumKind)] requires an associated enum_kind attribute to be specified");
-    let mut iter = params.iter();
-    if let Some(&NestedMeta::Meta(Meta::Path(ref path))) = iter.next() {
-        return (path.to_owned(), iter.cloned().collect());
-    } else {
-        panic!("#[enum_kind(NAME)] attribute requires NAME to be specified");
+    fn kind_name(&self) -> &'static str {
+        (**self).kind_name()
     }
 }
 
-fn has_docs(traits: &[NestedMeta]) -> bool {
-    traits.iter().any(|attr| {
-        if let NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, .. })) = attr {
-            path.is_ident("doc")
-        } else {
-            false
-        }
-    })
+/// Types that can be converted into their kind by value.
+///
+/// Implemented automatically by `#[derive(EnumKind)]`.
+pub trait IntoKind: ToKind {
+    /// Consumes this value and returns its kind.
+    fn into_kind(self) -> Self::Kind;
 }
 
-fn create_kind_enum(
-    definition: &DeriveInput,
-    kind_ident: &Path,
-    traits: Vec<NestedMeta>,
-) -> TokenStream {
-    let variant_idents = match &definition.data {
-        &Data::Enum(DataEnum { ref variants, .. }) => variants.iter().map(|ref v| v.ident.clone()),
-        _ => {
-            panic!("#[derive(EnumKind)] is only allowed for enums");
-        }
-    };
-    let visibility = &definition.vis;
-    let docs_attr = if !has_docs(traits.as_ref()) {
-        quote! {#[allow(missing_docs)]}
-    } else {
-        quote! {}
+/// Builds the set of the given kinds, for kind enums generated with the `set`
+/// option.
+///
+/// `kind_set!(SomeEnumKind: First | Third)` expands to a constant expression,
+/// so it can also initialize constants and statics.
+#[macro_export]
+macro_rules! kind_set {
+    ($kind:ident : $($variant:ident)|*) => {
+        $kind::set_of(&[$($kind::$variant),*])
     };
-    let code = quote! {
-        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-        #[allow(dead_code)]
-        #docs_attr
-        #( #[#traits] )*
-        #visibility enum #kind_ident {
-            #(#variant_idents),*
-        }
-    };
-    TokenStream::from(code)
 }
 
-fn is_uninhabited_enum(definition: &DeriveInput) -> bool {
-    if let Data::Enum(ref data) = definition.data {
-        return data.variants.len() == 0;
+/// Returns the kind of `value`.
+pub fn kind_of<T: ToKind>(value: &T) -> T::Kind {
+    value.kind()
+}
+
+/// Kind queries on slices of values that have a kind.
+pub trait KindSliceExt<T: ToKind> {
+    /// Returns `true` if any value in the slice has the given kind.
+    fn any_kind(&self, kind: T::Kind) -> bool;
+}
+
+impl<T: ToKind> KindSliceExt<T> for [T]
+where
+    T::Kind: PartialEq,
+{
+    fn any_kind(&self, kind: T::Kind) -> bool {
+        self.iter().any(|value| value.kind() == kind)
     }
-    return false;
 }
 
-fn create_impl(definition: &DeriveInput, kind_ident: &Path) -> TokenStream {
-    let (_, ty_generics, where_clause) = definition.generics.split_for_impl();
-    let ident = &definition.ident;
-
-    let arms = match &definition.data {
-        &Data::Enum(DataEnum { ref variants, .. }) => variants.iter().map(|ref v| {
-            let variant = &v.ident;
-            match v.fields {
-                Fields::Unit => quote! {
-                    &#ident::#variant => #kind_ident::#variant,
-                },
-                Fields::Unnamed(_) => quote! {
-                    &#ident::#variant(..) => #kind_ident::#variant,
-                },
-                Fields::Named(_) => quote! {
-                    &#ident::#variant{..} => #kind_ident::#variant,
-                },
-            }
-        }),
-        _ => {
-            panic!("#[derive(EnumKind)] is only allowed for enums");
-        }
-    };
+/// Kind queries on optional values.
+pub trait OptionKindExt<T: ToKind> {
+    /// Returns the kind of the contained value, if there is one.
+    fn map_kind(&self) -> Option<T::Kind>;
+}
 
-    let trait_: Path = if cfg!(feature = "no-stdlib") {
-        parse_quote!(::core::convert::From)
-    } else {
-        parse_quote!(::std::convert::From)
-    };
+impl<T: ToKind> OptionKindExt<T> for Option<T> {
+    fn map_kind(&self) -> Option<T::Kind> {
+        self.as_ref().map(ToKind::kind)
+    }
+}
 
-    let mut counter: u32 = 1;
-    let used: HashSet<Lifetime> = definition
-        .generics
-        .lifetimes()
-        .map(|ld| ld.lifetime.clone())
-        .collect();
-    let a = loop {
-        let lifetime: Lifetime = syn::parse_str(&format!("'__enum_kinds{}", counter)).unwrap();
-        if !used.contains(&lifetime) {
-            break LifetimeDef::new(lifetime);
-        }
-        counter += 1;
-    };
+/// Kind queries on results.
+pub trait ResultKindExt<T: ToKind, E> {
+    /// Returns the kind of the success value, or a reference to the error.
+    fn map_kind(&self) -> Result<T::Kind, &E>;
 
-    let mut generics = definition.generics.clone();
-    generics.params.insert(0, GenericParam::Lifetime(a.clone()));
-    let (impl_generics, _, _) = generics.split_for_impl();
-
-    let impl_ = if is_uninhabited_enum(definition) {
-        quote! {
-            unreachable!();
-        }
-    } else {
-        quote! {
-            match _value {
-                #(#arms)*
-            }
-        }
-    };
+    /// Returns the kind of the success value, or `None` for an error.
+    fn as_kind_opt(&self) -> Option<T::Kind>;
+}
 
-    let tokens = quote! {
-        #[automatically_derived]
-        #[allow(unused_attributes)]
-        impl #impl_generics #trait_<&#a #ident#ty_generics> for #kind_ident #where_clause {
-            fn from(_value: &#a #ident#ty_generics) -> Self {
-                #impl_
-            }
-        }
-
-        #[automatically_derived]
-        #[allow(unused_attributes)]
-        impl #impl_generics #trait_<#ident#ty_generics> for #kind_ident #where_clause {
-            fn from(value: #ident#ty_generics) -> Self {
-                #kind_ident::from(&value)
-            }
-        }
-    };
-    TokenStream::from(tokens)
+impl<T: ToKind, E> ResultKindExt<T, E> for Result<T, E> {
+    fn map_kind(&self) -> Result<T::Kind, &E> {
+        self.as_ref().map(ToKind::kind)
+    }
+
+    fn as_kind_opt(&self) -> Option<T::Kind> {
+        self.as_ref().ok().map(ToKind::kind)
+    }
 }
@@ -6,6 +6,7 @@ use std::fmt::Debug;
 #[macro_use]
 extern crate serde_derive;
 extern crate serde;
+extern crate serde as renamed_serde;
 extern crate serde_json;
 
 #[derive(EnumKind)]
@@ -45,91 +46,2468 @@ where
 }
 
 #[derive(EnumKind)]
-#[enum_kind(WithCollisionKind)]
+#[enum_kind(WithLifetimeBoundsKind)]
 #[allow(dead_code)]
-enum WithCollision<'__enum_kinds1> {
-    First(&'__enum_kinds1 str),
+enum WithLifetimeBounds<'a, 'b: 'a, T>
+where
+    'b: 'a,
+    T: Debug + 'b,
+{
+    First { short: &'a T, long: &'b T },
+    Second(&'b str),
 }
 
 #[derive(EnumKind)]
-#[enum_kind(UninhabitedEnumKind)]
+#[enum_kind(WithMixedGenericsKind)]
 #[allow(dead_code)]
-enum UninhabitedEnum {}
+enum WithMixedGenerics<'a, const N: usize, T>
+where
+    T: 'a,
+{
+    First(&'a [T; N]),
+    Second { values: [T; N] },
+}
 
 #[derive(EnumKind)]
-#[enum_kind(WithExtraTraitsKind, derive(Serialize, Deserialize))]
+#[enum_kind(WithHashKind, hash, std_discriminant, with_kind, toggle)]
 #[allow(dead_code)]
-enum WithExtraTraits {
-    First(u32, u32),
-    Second(String),
+enum WithHash {
+    First(i32),
+    Second(bool),
 }
 
 #[derive(EnumKind)]
-#[enum_kind(WithExtraTraitsMultipleKind, derive(Serialize), derive(Deserialize))]
+#[enum_kind(WithSkippedDiscriminantsKind, catch_all, std_discriminant)]
 #[allow(dead_code)]
-enum WithExtraTraitsMultiple {
-    First(u32, u32),
+enum WithSkippedDiscriminants {
+    First(i32),
+    #[enum_kind_skip]
+    Second,
+    #[enum_kind_skip]
+    Third,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithOwnMethodsKind)]
+#[allow(dead_code)]
+enum WithOwnMethods {
+    First(i32),
+    Second,
+}
+
+impl std::ops::Not for WithOwnMethodsKind {
+    type Output = bool;
+
+    fn not(self) -> bool {
+        self == WithOwnMethodsKind::Second
+    }
+}
+
+impl WithOwnMethods {
+    fn std_discriminant(&self) -> &'static str {
+        "own"
+    }
+
+    fn with_kind(self) -> Self {
+        self
+    }
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithVariantDocsKind)]
+#[allow(dead_code)]
+enum WithVariantDocs {
+    /// The first variant.
+    ///
+    /// It has a second paragraph.
+    First(i32),
+    Second,
+}
+
+macro_rules! stamp_enum {
+    ($name:ident, $kind:ident) => {
+        #[derive(EnumKind)]
+        #[enum_kind($kind, map)]
+        #[allow(dead_code)]
+        enum $name {
+            First(i32),
+            Second,
+        }
+    };
+}
+
+stamp_enum!(StampedFirst, StampedFirstKind);
+stamp_enum!(StampedSecond, StampedSecondKind);
+
+// Appends variants to an enum before any of its derives run.
+macro_rules! with_extra_variants {
+    ($(#[$attr:meta])* enum $name:ident { $($variants:tt)* }) => {
+        $(#[$attr])*
+        enum $name {
+            $($variants)*
+            Extra(u8),
+            Another { value: bool },
+        }
+    };
+}
+
+with_extra_variants! {
+    #[derive(Debug, Clone, EnumKind)]
+    #[enum_kind(AugmentedKind)]
+    #[allow(dead_code)]
+    enum Augmented {
+        First(i32),
+        #[cfg(any())]
+        Removed,
+        Second,
+    }
+}
+
+#[cfg_attr(test, derive(EnumKind))]
+#[cfg_attr(test, enum_kind(ConditionalKind))]
+#[allow(dead_code)]
+enum Conditional {
+    First(i32),
+    #[cfg(test)]
+    Kept,
+}
+
+#[cfg(feature = "serde")]
+#[derive(EnumKind)]
+#[enum_kind(WithSerdeIndexKind, serde_as_index, derive_all)]
+#[allow(dead_code)]
+enum WithSerdeIndex {
+    First(i32),
+    Second,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithOrdKind, ord, repr = "u8")]
+#[allow(dead_code)]
+enum WithOrd {
+    #[enum_kind_value(2)]
+    First,
+    #[enum_kind_value(1)]
+    Second(i32),
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithRenamesKind, derive(Serialize))]
+#[allow(dead_code)]
+enum WithRenames {
+    #[enum_kind_rename(New)]
+    Old(i32),
+    #[enum_kind_rename(Renamed)]
+    #[enum_kind_attr(serde(rename = "custom"))]
+    Other,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(TransparentKind)]
+#[repr(transparent)]
+#[allow(dead_code)]
+enum Transparent {
+    Only(u32),
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithDisplayKind, display)]
+#[allow(dead_code)]
+enum WithDisplay {
+    #[enum_kind_value(label = "first")]
+    First(i32),
+    Second,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithPartialValuesKind, repr = "u8")]
+#[allow(dead_code)]
+enum WithPartialValues {
+    First,
+    #[enum_kind_value(5)]
+    Second(i32),
+    Third,
+    #[enum_kind_value(10)]
+    Fourth,
+    Fifth(bool),
+}
+
+/// Documented before the kind attribute.
+#[derive(EnumKind, Clone, Debug)]
+#[allow(dead_code)]
+#[enum_kind(KindAfterOthersKind, derive(Hash))]
+enum KindAfterOthers {
+    First(i32),
+    Second,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(KindBeforeOthersKind, derive(Hash))]
+/// Documented after the kind attribute.
+#[derive(Clone)]
+#[allow(dead_code)]
+enum KindBeforeOthers {
+    First(i32),
+    Second,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithEmptyDeriveKind, derive(), derive(Hash))]
+#[allow(dead_code)]
+enum WithEmptyDerive {
+    First(i32),
+    Second,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithSnakeNamesKind, name_style = "snake", display)]
+#[allow(dead_code)]
+enum WithSnakeNames {
+    FirstKind(i32),
+    HTTPServer,
+    V2,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithKebabNamesKind, name_style = "kebab", display)]
+#[allow(dead_code)]
+enum WithKebabNames {
+    FirstKind(i32),
+    HTTPServer,
+    V2,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithScreamingSnakeNamesKind, name_style = "SCREAMING_SNAKE", display)]
+#[allow(dead_code)]
+enum WithScreamingSnakeNames {
+    FirstKind(i32),
+    HTTPServer,
+    V2,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithCamelNamesKind, name_style = "camel", display)]
+#[allow(dead_code)]
+enum WithCamelNames {
+    FirstKind(i32),
+    HTTPServer,
+    V2,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithPascalNamesKind, name_style = "pascal", display)]
+#[allow(dead_code)]
+enum WithPascalNames {
+    FirstKind(i32),
+    HTTPServer,
+    V2,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithDefaultVariantKind, derive(Default), default_variant = "Second")]
+#[allow(dead_code)]
+enum WithDefaultVariant {
+    First(i32),
+    Second,
+    Third(bool),
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithImpliedDefaultKind, default_variant = "Other", catch_all)]
+#[allow(dead_code)]
+enum WithImpliedDefault {
+    First(i32),
+    #[enum_kind_skip]
+    Second,
+}
+
+#[derive(EnumKind)]
+#[enum_kind("WithStringNameKind", derive(Hash))]
+#[allow(dead_code)]
+enum WithStringName {
+    First(i32),
+    Second,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(HashedByKindKind, hash_original_by_kind)]
+#[allow(dead_code)]
+enum HashedByKind {
+    First(i32),
     Second(String),
 }
 
-mod forbids_missing_docs {
-    #![forbid(missing_docs)]
+#[derive(EnumKind)]
+#[enum_kind(WithConstBoundKind)]
+#[allow(dead_code)]
+enum WithConstBound<'a, T, const N: usize>
+where
+    [T; N]: Default + Clone,
+    T: Copy + 'a,
+{
+    Array([T; N]),
+    Slice(&'a [T]),
+    Empty,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithSelfReferenceKind)]
+#[allow(dead_code)]
+enum WithSelfReference<K>
+where
+    K: Into<WithSelfReferenceKind>,
+{
+    Wrapped(K),
+    Empty,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(
+    WithRenamedSerdeKind,
+    serde_crate = "renamed_serde",
+    derive(renamed_serde::Serialize)
+)]
+#[allow(dead_code)]
+enum WithRenamedSerde {
+    First(i32),
+    Second,
+}
+
+#[cfg(feature = "serde")]
+#[derive(EnumKind)]
+#[enum_kind(
+    WithRenamedSerdeDeriveAllKind,
+    serde_crate = "renamed_serde",
+    derive_all
+)]
+#[allow(dead_code)]
+enum WithRenamedSerdeDeriveAll {
+    First(i32),
+    Second,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(TreeKind, map)]
+#[allow(dead_code)]
+enum Tree {
+    Leaf(u32),
+    Node(Box<Tree>, Box<Tree>),
+    Children { children: Vec<Self> },
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithSetKind, set)]
+#[allow(dead_code)]
+enum WithSet {
+    First(i32),
+    Second,
+    Third(bool),
+    Fourth,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(
+    WithHelperAttrKind,
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[allow(dead_code)]
+enum WithHelperAttr {
+    FirstVariant(i32),
+    SecondVariant,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithReprHashKind, repr = "u8", hash)]
+#[allow(dead_code)]
+#[repr(u8)]
+enum WithReprHash {
+    First(i32) = 3,
+    Second = 7,
+    Third(bool) = 1,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithMixedSkipsKind, catch_all)]
+#[allow(dead_code)]
+enum WithMixedSkips {
+    First(u32),
+    Second {
+        x: u8,
+    },
+    Third,
+    #[enum_kind_skip]
+    Fourth(u16, bool),
+    #[enum_kind_skip]
+    Fifth {
+        y: char,
+    },
+    #[enum_kind_skip]
+    Sixth,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(
+    WithEverythingKind,
+    ord,
+    hash,
+    derive_all,
+    derive(Hash, Serialize, Deserialize)
+)]
+#[allow(dead_code)]
+enum WithEverything {
+    First(u32),
+    Second { value: String },
+    Third,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithNonExhaustiveReprKind, repr = "u8", non_exhaustive)]
+#[allow(dead_code)]
+enum WithNonExhaustiveRepr {
+    #[enum_kind_value(10)]
+    First(u32),
+    Second,
+    #[enum_kind_value(20)]
+    Third {
+        value: bool,
+    },
+}
+
+#[derive(EnumKind)]
+#[enum_kind(SequentialReprKind, repr = "u8")]
+#[allow(dead_code)]
+#[repr(u8)]
+enum SequentialRepr {
+    A(u32),
+    B,
+    C { value: bool },
+}
+
+#[derive(EnumKind)]
+#[enum_kind(SkippedReprKind, repr = "u8", catch_all)]
+#[allow(dead_code)]
+#[repr(u8)]
+enum SkippedRepr {
+    A(u32),
+    #[enum_kind_skip]
+    B,
+    #[enum_kind_skip]
+    C(bool),
+    D,
+    E = 10,
+    #[enum_kind_skip]
+    F,
+    G,
+}
+
+mod doc_alias {
+    #![deny(warnings)]
 
     #[derive(EnumKind)]
-    #[enum_kind(WithDocumentationKind, doc = "a documented kind enum")]
+    #[enum_kind(WithDocAliasKind, doc_alias, inherit_docs)]
     #[allow(dead_code)]
-    enum WithDocumentation {
-        First(u32, u32),
-        Second(String),
+    /// An enum found by searching for its kind.
+    pub enum WithDocAlias {
+        /// The first variant.
+        First(u32),
+        /// The second variant.
+        Second,
     }
 }
 
-#[test]
-fn test_unnamed() {
-    let first = UnnamedEnum::First("Example".to_owned(), 32);
-    assert_eq!(UnnamedEnumKind::from(first), UnnamedEnumKind::First);
+#[derive(EnumKind)]
+#[enum_kind(WithArrayKind, array)]
+#[allow(dead_code)]
+enum WithArray {
+    First(i32),
+    Second,
+    Third { value: bool },
 }
 
-#[test]
-fn test_named() {
-    let foo = NamedEnum::Foo {
-        foo: "Example".to_owned(),
-        bar: 32,
-    };
-    assert_eq!(NamedEnumKind::from(&foo), NamedEnumKind::Foo);
+#[derive(EnumKind)]
+#[enum_kind(WithPartialGroupsKind, no_copy)]
+#[allow(dead_code)]
+enum WithPartialGroups {
+    #[enum_kind_group(Small)]
+    Byte(u8),
+    Ungrouped,
+    #[enum_kind_group(Small)]
+    Flag(bool),
 }
 
-#[test]
-fn test_with_lifetimes() {
-    let first = WithLifetime::First("hello");
-    assert_eq!(WithLifetimeKind::from(&first), WithLifetimeKind::First);
+#[cfg(feature = "valuable")]
+#[derive(EnumKind)]
+#[enum_kind(WithValuableKind, valuable)]
+#[allow(dead_code)]
+enum WithValuable {
+    First(u32),
+    Second { value: bool },
 }
 
-#[test]
-fn test_with_where_clause() {
-    let first = WithWhereClause::First { value: "hello" };
-    assert_eq!(
-        WithWhereClauseKind::from(&first),
-        WithWhereClauseKind::First
-    );
+#[derive(EnumKind)]
+#[enum_kind(WithConversionsKind, conversions)]
+#[allow(dead_code)]
+enum WithConversions {
+    First(u32),
+    Second,
 }
 
-#[test]
-fn test_with_collision() {
-    let first = WithCollision::First("hello");
-    assert_eq!(WithCollisionKind::from(&first), WithCollisionKind::First);
+#[derive(EnumKind)]
+#[enum_kind(WithDebugValueKind, repr = "u8", debug_with_value)]
+#[allow(dead_code)]
+enum WithDebugValue {
+    #[enum_kind_value(1)]
+    First(u32),
+    #[enum_kind_value(7)]
+    Second,
 }
 
-#[test]
-fn test_with_extra_traits() {
-    let first = WithExtraTraits::First(20, 30);
-    let kind: WithExtraTraitsKind = first.into();
-    serde_json::to_string(&kind).unwrap();
+#[derive(EnumKind)]
+#[enum_kind(WithOwnedOnlyKind, owned_only, catch_all)]
+#[allow(dead_code)]
+enum WithOwnedOnly<T> {
+    First(T),
+    Second {
+        value: T,
+    },
+    #[enum_kind_skip]
+    Third,
 }
 
-#[test]
-fn test_with_extra_traits_multiple() {
-    let first = WithExtraTraitsMultiple::First(20, 30);
-    let kind: WithExtraTraitsMultipleKind = first.into();
-    serde_json::to_string(&kind).unwrap();
+#[derive(EnumKind)]
+#[enum_kind(WithFallibleKind, fallible)]
+#[allow(dead_code)]
+enum WithFallible<T> {
+    First(T),
+    #[enum_kind_skip]
+    Second {
+        value: T,
+    },
+    Third,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithFallibleCatchAllKind, fallible, catch_all)]
+#[allow(dead_code)]
+enum WithFallibleCatchAll {
+    First(u32),
+    #[enum_kind_skip]
+    Second,
+}
+
+#[derive(EnumKind, Debug, Clone, Copy, PartialEq)]
+#[enum_kind(FieldlessKind, mirror_repr)]
+#[allow(dead_code)]
+#[repr(i16)]
+enum Fieldless {
+    First = -3,
+    Second,
+    Third = 1000,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithPredicatesKind, predicates, catch_all)]
+#[allow(dead_code)]
+enum WithPredicates {
+    First(u32),
+    SecondVariant {
+        value: bool,
+    },
+    HTTPRequest,
+    #[enum_kind_skip]
+    Skipped,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithCollisionKind)]
+#[allow(dead_code)]
+enum WithCollision<'__enum_kinds1> {
+    First(&'__enum_kinds1 str),
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithCustomLifetimeKind, lifetime = "kref")]
+#[allow(dead_code)]
+enum WithCustomLifetime<'a> {
+    First(&'a str),
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithCustomLifetimeCollisionKind, lifetime = "kref")]
+#[allow(dead_code)]
+enum WithCustomLifetimeCollision<'kref, 'kref1> {
+    First(&'kref str),
+    Second(&'kref1 str),
+}
+
+#[derive(EnumKind)]
+#[enum_kind(UninhabitedEnumKind)]
+#[allow(dead_code)]
+enum UninhabitedEnum {}
+
+#[derive(EnumKind)]
+#[enum_kind(WithExtraTraitsKind, derive(Serialize, Deserialize))]
+#[allow(dead_code)]
+enum WithExtraTraits {
+    First(u32, u32),
+    Second(String),
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithExtraTraitsMultipleKind, derive(Serialize), derive(Deserialize))]
+#[allow(dead_code)]
+enum WithExtraTraitsMultiple {
+    First(u32, u32),
+    Second(String),
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithCatchAllCharKind, catch_all, catch_all_char = '?')]
+#[allow(dead_code)]
+enum WithCatchAllChar {
+    #[enum_kind_char('F')]
+    First(u32),
+    #[enum_kind_skip]
+    Skipped,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithCharsKind)]
+#[allow(dead_code)]
+enum WithChars {
+    #[enum_kind_char('F')]
+    First(u32),
+    #[enum_kind_char('s')]
+    Second { value: String },
+    #[enum_kind_char('3')]
+    Third,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithoutCopyKind, no_copy, toggle)]
+#[allow(dead_code)]
+enum WithoutCopy {
+    #[enum_kind_char('F')]
+    First(u32),
+    #[enum_kind_char('S')]
+    Second,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithIndexTypeKind, index_type = "u8")]
+#[allow(dead_code)]
+enum WithIndexType {
+    First(u32),
+    Second,
+    Third { value: String },
+}
+
+#[derive(EnumKind)]
+#[enum_kind(SingleVariantKind, auto_default)]
+#[allow(dead_code)]
+enum SingleVariant {
+    Only(String),
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithReprKind, repr = "u8")]
+#[allow(dead_code)]
+enum WithRepr {
+    First(u32),
+    #[enum_kind_value(10)]
+    Second,
+    Third {
+        value: String,
+    },
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithOrderingKind, derive(PartialOrd, Ord, Hash))]
+#[allow(dead_code)]
+enum WithOrdering {
+    First(u32),
+    Second,
+    Third { value: String },
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithBoundKind, derive(Serialize), bound = "u32: serde::Serialize")]
+#[allow(dead_code)]
+enum WithBound {
+    First(u32),
+    Second,
+}
+
+#[derive(EnumKind, Clone)]
+#[enum_kind(WithOriginalBoundKind, bound = "WithOriginalBound: Clone, u8: Copy")]
+#[allow(dead_code)]
+enum WithOriginalBound {
+    First(u32),
+    Second,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithDiscriminantsKind, repr = "u8")]
+#[allow(dead_code)]
+enum WithDiscriminants {
+    First = 1,
+    Second = 5,
+    #[enum_kind_value(9)]
+    Third = 7,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithDeriveAllKind, derive_all)]
+#[allow(dead_code)]
+enum WithDeriveAll {
+    First(u32),
+    Second,
+}
+
+#[cfg(not(feature = "serde"))]
+#[derive(EnumKind)]
+#[enum_kind(WithDeriveAllAndMoreKind, derive_all, derive(Serialize))]
+#[allow(dead_code)]
+enum WithDeriveAllAndMore {
+    First(u32),
+    Second,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithAliasesKind)]
+#[allow(dead_code)]
+enum WithAliases {
+    #[enum_kind_alias("frst")]
+    #[enum_kind_alias("1st")]
+    First(u32),
+    Second,
+}
+
+struct NotCloneOrDebug;
+
+#[derive(EnumKind)]
+#[enum_kind(WithOpaquePayloadKind, derive_all)]
+#[allow(dead_code)]
+enum WithOpaquePayload {
+    First(NotCloneOrDebug),
+    Second { value: NotCloneOrDebug },
+    Third,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithSelfTestKind, self_test, no_copy, index_type = "u8")]
+#[allow(dead_code)]
+enum WithSelfTest {
+    First(u32),
+    Second,
+    Third { value: String },
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithCatchAllKind, catch_all, derive(Serialize, Deserialize))]
+#[allow(dead_code)]
+enum WithCatchAll {
+    First(u32),
+    #[enum_kind_skip]
+    Second,
+    #[enum_kind_skip]
+    Third {
+        value: String,
+    },
+}
+
+#[cfg(feature = "serde")]
+#[derive(EnumKind)]
+#[enum_kind(WithCatchAllDeriveAllKind, catch_all = "Unknown", derive_all)]
+#[allow(dead_code)]
+enum WithCatchAllDeriveAll {
+    First(u32),
+    #[enum_kind_skip]
+    Second,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithMapKind, map)]
+#[allow(dead_code)]
+enum WithMap {
+    First(u32),
+    Second,
+    Third { value: String },
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithEqByNameKind, eq_by_name, derive(Hash, PartialOrd))]
+#[allow(dead_code)]
+enum WithEqByName {
+    First(u32),
+    Second,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithNonExhaustiveKind, catch_all, non_exhaustive)]
+#[allow(dead_code)]
+#[non_exhaustive]
+enum WithNonExhaustive {
+    First(u32),
+    Second {
+        value: String,
+    },
+    #[enum_kind_skip]
+    Third,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithInherentOnlyKind, inherent_only)]
+#[allow(dead_code)]
+enum WithInherentOnly<'a, T> {
+    First(&'a T),
+    Second,
+}
+
+mod crate_visible {
+    #![deny(private_interfaces, private_bounds)]
+
+    #[derive(EnumKind)]
+    #[enum_kind(CrateVisibleKind, map)]
+    #[allow(dead_code)]
+    pub(crate) enum CrateVisible {
+        First(u32),
+        Second,
+    }
+}
+
+mod shapes {
+    #[derive(EnumKind)]
+    #[enum_kind(ShapeKind)]
+    #[allow(dead_code)]
+    pub enum Shape {
+        Circle(f64),
+        Square(f64),
+    }
+}
+
+mod kinds {
+    pub use crate::shapes::{ParseShapeKindError, ShapeKind};
+}
+
+pub mod variant_lints {
+    //! The kind enum allows `missing_docs` itself unless it inherits docs, so
+    //! only the forwarded variant attribute keeps `First` from failing here.
+    #![deny(missing_docs)]
+
+    /// An enum with an undocumented variant.
+    #[derive(EnumKind)]
+    #[enum_kind(VariantLintsKind, inherit_docs)]
+    #[allow(dead_code)]
+    pub enum VariantLints {
+        #[allow(missing_docs)]
+        First(i32),
+        /// The second variant.
+        Second,
+    }
+}
+
+mod unconventional_names {
+    #![deny(warnings)]
+
+    #[derive(EnumKind)]
+    #[enum_kind(UnconventionalKind)]
+    #[allow(dead_code, non_camel_case_types)]
+    pub enum Unconventional {
+        snake_case(i32),
+        lower,
+        Regular,
+    }
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithoutDebugKind, no_debug, self_test, map)]
+#[allow(dead_code)]
+enum WithoutDebug {
+    First(u32),
+    Second,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithGroupsKind, group_kind = "WithGroupsGroup", catch_all)]
+#[allow(dead_code)]
+enum WithGroups {
+    #[enum_kind_group(Number)]
+    Integer(i64),
+    #[enum_kind_group(Number)]
+    Float(f64),
+    #[enum_kind_group(Text)]
+    String(String),
+    #[enum_kind_skip]
+    Nothing,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithGroupsByNameKind, group_kind = "WithGroupsByNameGroup", eq_by_name)]
+#[allow(dead_code)]
+enum WithGroupsByName {
+    #[enum_kind_group(Number)]
+    Integer(i64),
+    #[enum_kind_group(Text)]
+    String(String),
+}
+
+#[derive(EnumKind)]
+#[enum_kind(WithValueKeysKind, repr = "u8")]
+#[allow(dead_code)]
+enum WithValueKeys {
+    #[enum_kind_value(code = 5, label = "five", alias = "V")]
+    Five(u32),
+    #[enum_kind_value(label = "six")]
+    Six,
+    #[enum_kind_value(10)]
+    Ten,
+}
+
+mod forbids_missing_docs {
+    #![forbid(missing_docs)]
+
+    #[derive(EnumKind)]
+    #[enum_kind(WithDocumentationKind, doc = "a documented kind enum")]
+    #[allow(dead_code)]
+    enum WithDocumentation {
+        First(u32, u32),
+        Second(String),
+    }
+}
+
+mod denies_missing_docs {
+    #![deny(missing_docs)]
+
+    /// An enum whose documentation is inherited by its kind.
+    #[derive(EnumKind)]
+    #[enum_kind(WithInheritedDocumentationKind, inherit_docs)]
+    #[allow(dead_code)]
+    enum WithInheritedDocumentation {
+        First(u32, u32),
+        Second(String),
+    }
+}
+
+pub mod denies_missing_variant_docs {
+    //! Kinds with documentation given in the attribute.
+    #![deny(missing_docs)]
+
+    /// An enum with one intentionally undocumented variant.
+    #[derive(EnumKind)]
+    #[enum_kind(
+        WithVariantDocumentationKind,
+        doc = "a documented kind enum",
+        map,
+        catch_all
+    )]
+    pub enum WithVariantDocumentation {
+        /// The first variant.
+        First(u32),
+        #[allow(missing_docs)]
+        Second(String),
+        /// A skipped variant.
+        #[enum_kind_skip]
+        Third,
+    }
+}
+
+#[test]
+fn test_unnamed() {
+    let first = UnnamedEnum::First("Example".to_owned(), 32);
+    assert_eq!(UnnamedEnumKind::from(first), UnnamedEnumKind::First);
+}
+
+#[test]
+fn test_named() {
+    let foo = NamedEnum::Foo {
+        foo: "Example".to_owned(),
+        bar: 32,
+    };
+    assert_eq!(NamedEnumKind::from(&foo), NamedEnumKind::Foo);
+}
+
+#[test]
+fn test_with_lifetimes() {
+    let first = WithLifetime::First("hello");
+    assert_eq!(WithLifetimeKind::from(&first), WithLifetimeKind::First);
+}
+
+#[test]
+fn test_with_where_clause() {
+    let first = WithWhereClause::First { value: "hello" };
+    assert_eq!(
+        WithWhereClauseKind::from(&first),
+        WithWhereClauseKind::First
+    );
+}
+
+#[test]
+fn test_with_lifetime_bounds() {
+    let long = 10u32;
+    let first = {
+        let short = 20u32;
+        WithLifetimeBoundsKind::from(&WithLifetimeBounds::First {
+            short: &short,
+            long: &long,
+        })
+    };
+    assert_eq!(first, WithLifetimeBoundsKind::First);
+    let second: WithLifetimeBounds<u32> = WithLifetimeBounds::Second("hello");
+    assert_eq!(
+        WithLifetimeBoundsKind::from(second),
+        WithLifetimeBoundsKind::Second
+    );
+}
+
+#[test]
+fn test_with_mixed_generics() {
+    use enum_kinds::ToKind;
+    let values = [1u8, 2, 3];
+    let first = WithMixedGenerics::First(&values);
+    assert_eq!(
+        WithMixedGenericsKind::from(&first),
+        WithMixedGenericsKind::First
+    );
+    assert_eq!(first.kind(), WithMixedGenericsKind::First);
+    let second: WithMixedGenerics<2, char> = WithMixedGenerics::Second { values: ['a', 'b'] };
+    assert_eq!(
+        WithMixedGenericsKind::from(second),
+        WithMixedGenericsKind::Second
+    );
+}
+
+#[test]
+fn test_with_collision() {
+    let first = WithCollision::First("hello");
+    assert_eq!(WithCollisionKind::from(&first), WithCollisionKind::First);
+}
+
+#[test]
+fn test_with_custom_lifetime() {
+    let first = WithCustomLifetime::First("hello");
+    assert_eq!(
+        WithCustomLifetimeKind::from(&first),
+        WithCustomLifetimeKind::First
+    );
+    let second = WithCustomLifetimeCollision::Second("hello");
+    assert_eq!(
+        WithCustomLifetimeCollisionKind::from(&second),
+        WithCustomLifetimeCollisionKind::Second
+    );
+}
+
+#[test]
+fn test_with_extra_traits() {
+    let first = WithExtraTraits::First(20, 30);
+    let kind: WithExtraTraitsKind = first.into();
+    serde_json::to_string(&kind).unwrap();
+}
+
+#[test]
+fn test_with_extra_traits_multiple() {
+    let first = WithExtraTraitsMultiple::First(20, 30);
+    let kind: WithExtraTraitsMultipleKind = first.into();
+    serde_json::to_string(&kind).unwrap();
+}
+
+#[test]
+fn test_with_chars() {
+    use std::convert::TryFrom;
+    for &kind in &[
+        WithCharsKind::First,
+        WithCharsKind::Second,
+        WithCharsKind::Third,
+    ] {
+        assert_eq!(WithCharsKind::try_from(kind.to_char()), Ok(kind));
+    }
+    assert_eq!(WithCharsKind::from(WithChars::First(1)).to_char(), 'F');
+    let error = WithCharsKind::try_from('x').unwrap_err();
+    assert_eq!(error.value(), 'x');
+    assert_eq!(error.to_string(), "invalid character 'x' for WithCharsKind");
+}
+
+#[test]
+fn test_chars_with_catch_all() {
+    use std::convert::TryFrom;
+    let other = WithCatchAllCharKind::from(WithCatchAllChar::Skipped);
+    assert_eq!(other.to_char(), '?');
+    assert_eq!(WithCatchAllCharKind::try_from('?'), Ok(other));
+    assert_eq!(
+        WithCatchAllCharKind::try_from('F'),
+        Ok(WithCatchAllCharKind::First)
+    );
+    assert!(WithCatchAllCharKind::try_from('x').is_err());
+}
+
+#[test]
+fn test_without_copy() {
+    use std::convert::TryFrom;
+    let first = WithoutCopyKind::from(WithoutCopy::First(1));
+    let cloned = first.clone();
+    assert_eq!(first, cloned);
+    assert_eq!(first.to_char(), 'F');
+    assert_eq!(WithoutCopyKind::try_from('S'), Ok(WithoutCopyKind::Second));
+}
+
+#[test]
+fn test_as_index() {
+    use std::convert::TryFrom;
+    assert_eq!(UnnamedEnumKind::First.as_index(), 0usize);
+    assert_eq!(UnnamedEnumKind::Third.as_index(), 2usize);
+    assert_eq!(
+        UnnamedEnumKind::try_from(1usize),
+        Ok(UnnamedEnumKind::Second)
+    );
+    assert_eq!(UnnamedEnumKind::try_from(3usize).unwrap_err().value(), 3);
+}
+
+#[test]
+fn test_with_index_type() {
+    use std::convert::TryFrom;
+    let index: u8 = WithIndexTypeKind::Third.as_index();
+    assert_eq!(index, 2);
+    assert_eq!(
+        WithIndexTypeKind::try_from(index),
+        Ok(WithIndexTypeKind::Third)
+    );
+    let error = WithIndexTypeKind::try_from(200u8).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "index 200 is out of range for WithIndexTypeKind"
+    );
+}
+
+#[test]
+fn test_auto_default() {
+    assert_eq!(SingleVariantKind::default(), SingleVariantKind::Only);
+    assert_eq!(
+        SingleVariantKind::from(SingleVariant::Only("hello".to_owned())),
+        SingleVariantKind::default()
+    );
+}
+
+#[test]
+fn test_index_conversions() {
+    use std::convert::TryFrom;
+    for &kind in &[
+        UnnamedEnumKind::First,
+        UnnamedEnumKind::Second,
+        UnnamedEnumKind::Third,
+    ] {
+        let index = usize::from(kind);
+        assert_eq!(index, kind.as_index());
+        assert_eq!(UnnamedEnumKind::try_from(index), Ok(kind));
+    }
+    assert_eq!(u8::from(WithIndexTypeKind::Second), 1);
+}
+
+#[test]
+fn test_repr_value() {
+    assert_eq!(WithReprKind::First.repr_value(), 0u8);
+    assert_eq!(WithReprKind::Second.repr_value(), 10u8);
+    assert_eq!(WithReprKind::Third.repr_value(), 11u8);
+    assert_eq!(WithReprKind::Second.as_index(), 1);
+    assert_eq!(WithReprKind::Third.as_index(), 2);
+    assert_eq!(std::mem::size_of::<WithReprKind>(), 1);
+}
+
+#[test]
+fn test_all() {
+    assert_eq!(UnnamedEnumKind::COUNT, 3);
+    assert_eq!(
+        UnnamedEnumKind::ALL,
+        [
+            UnnamedEnumKind::First,
+            UnnamedEnumKind::Second,
+            UnnamedEnumKind::Third
+        ]
+    );
+    assert_eq!(UninhabitedEnumKind::ALL.len(), 0);
+}
+
+#[cfg(not(feature = "no-stdlib"))]
+#[test]
+fn test_all_set() {
+    let set = WithOrderingKind::all_set();
+    assert_eq!(set.len(), WithOrderingKind::COUNT);
+    assert!(WithOrderingKind::ALL.iter().all(|kind| set.contains(kind)));
+    let hash_set = WithOrderingKind::all_hash_set();
+    assert_eq!(hash_set.len(), WithOrderingKind::COUNT);
+    assert!(WithOrderingKind::ALL
+        .iter()
+        .all(|kind| hash_set.contains(kind)));
+}
+
+#[test]
+fn test_with_bound() {
+    let kind = WithBoundKind::from(WithBound::First(10));
+    assert_eq!(serde_json::to_string(&kind).unwrap(), "\"First\"");
+}
+
+#[test]
+fn test_bound_naming_original() {
+    let kind = WithOriginalBoundKind::from(WithOriginalBound::First(1).clone());
+    assert_eq!(kind, WithOriginalBoundKind::First);
+}
+
+#[test]
+fn test_before_after() {
+    use UnnamedEnumKind::*;
+    assert!(First.before(Second));
+    assert!(First.before(Third));
+    assert!(Second.before(Third));
+    assert!(!Second.before(First));
+    assert!(!First.before(First));
+    assert!(Third.after(First));
+    assert!(Second.after(First));
+    assert!(!First.after(Second));
+    assert!(!Third.after(Third));
+    assert!(WithoutCopyKind::First.before(&WithoutCopyKind::Second));
+}
+
+#[test]
+fn test_with_discriminants() {
+    assert_eq!(WithDiscriminantsKind::First as u8, 1);
+    assert_eq!(WithDiscriminantsKind::Second as u8, 5);
+    assert_eq!(WithDiscriminantsKind::Third as u8, 9);
+    assert_eq!(
+        WithDiscriminantsKind::from(WithDiscriminants::Second).repr_value(),
+        5
+    );
+}
+
+#[test]
+fn test_derive_all() {
+    use std::collections::{BTreeSet, HashSet};
+    let kinds = [WithDeriveAllKind::Second, WithDeriveAllKind::First];
+    assert!(WithDeriveAllKind::First < WithDeriveAllKind::Second);
+    let ordered: BTreeSet<_> = kinds.iter().cloned().collect();
+    assert_eq!(
+        ordered.into_iter().collect::<Vec<_>>(),
+        WithDeriveAllKind::ALL.to_vec()
+    );
+    let hashed: HashSet<_> = kinds.iter().cloned().collect();
+    assert!(hashed.contains(&WithDeriveAllKind::First));
+    #[cfg(not(feature = "no-stdlib"))]
+    assert_eq!(WithDeriveAllKind::all_set().len(), 2);
+}
+
+#[cfg(not(feature = "serde"))]
+#[test]
+fn test_derive_all_and_more() {
+    let kind = WithDeriveAllAndMoreKind::from(WithDeriveAllAndMore::First(1));
+    assert!(kind < WithDeriveAllAndMoreKind::Second);
+    assert_eq!(serde_json::to_string(&kind).unwrap(), "\"First\"");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_derive_all_serde() {
+    let kind = WithDeriveAllKind::from(WithDeriveAll::First(1));
+    let json = serde_json::to_string(&kind).unwrap();
+    assert_eq!(json, "\"First\"");
+    assert_eq!(
+        serde_json::from_str::<WithDeriveAllKind>(&json).unwrap(),
+        kind
+    );
+}
+
+#[test]
+fn test_names() {
+    assert_eq!(UnnamedEnumKind::First.name(), "First");
+    assert_eq!(UnnamedEnumKind::VARIANT_NAMES, ["First", "Second", "Third"]);
+    assert_eq!(
+        UnnamedEnumKind::from_name("Second"),
+        Some(UnnamedEnumKind::Second)
+    );
+    assert_eq!(UnnamedEnumKind::from_name("second"), None);
+    assert_eq!("Third".parse(), Ok(UnnamedEnumKind::Third));
+    let error = "Fourth".parse::<UnnamedEnumKind>().unwrap_err();
+    assert_eq!(error.to_string(), "invalid UnnamedEnumKind name");
+}
+
+#[test]
+fn test_errors() {
+    use std::convert::TryFrom;
+    use std::error::Error;
+    let errors: Vec<Box<dyn Error>> = vec![
+        Box::new("Fourth".parse::<UnnamedEnumKind>().unwrap_err()),
+        Box::new(UnnamedEnumKind::try_from(10usize).unwrap_err()),
+        Box::new(WithCharsKind::try_from('x').unwrap_err()),
+    ];
+    assert!(errors.iter().all(|error| error.source().is_none()));
+}
+
+#[test]
+fn test_with_aliases() {
+    assert_eq!("First".parse(), Ok(WithAliasesKind::First));
+    assert_eq!("frst".parse(), Ok(WithAliasesKind::First));
+    assert_eq!(
+        WithAliasesKind::from_name("1st"),
+        Some(WithAliasesKind::First)
+    );
+    assert_eq!("Second".parse(), Ok(WithAliasesKind::Second));
+    assert_eq!(WithAliasesKind::First.name(), "First");
+    assert!("scnd".parse::<WithAliasesKind>().is_err());
+}
+
+#[test]
+fn test_kind_of() {
+    use enum_kinds::{kind_of, ToKind};
+    let unnamed = UnnamedEnum::Second('a');
+    assert_eq!(kind_of(&unnamed), UnnamedEnumKind::Second);
+    assert_eq!(unnamed.kind(), UnnamedEnumKind::Second);
+    let bar = NamedEnum::Bar { zap: 'z' };
+    assert_eq!(kind_of(&bar), NamedEnumKind::Bar);
+    let first = WithLifetime::First("hello");
+    assert_eq!(kind_of(&first), WithLifetimeKind::First);
+}
+
+#[test]
+fn test_with_opaque_payload() {
+    use enum_kinds::ToKind;
+    let first = WithOpaquePayload::First(NotCloneOrDebug);
+    let kind = first.kind();
+    assert_eq!(kind, WithOpaquePayloadKind::First);
+    assert_eq!(kind.clone(), WithOpaquePayloadKind::from(&first));
+    assert_eq!(format!("{:?}", kind), "First");
+    let second = WithOpaquePayload::Second {
+        value: NotCloneOrDebug,
+    };
+    assert_eq!(
+        WithOpaquePayloadKind::from(second),
+        WithOpaquePayloadKind::Second
+    );
+}
+
+#[test]
+fn test_iter() {
+    let mut iter = UnnamedEnumKind::iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next(), Some(UnnamedEnumKind::First));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(
+        iter.collect::<Vec<_>>(),
+        vec![UnnamedEnumKind::Second, UnnamedEnumKind::Third]
+    );
+    assert_eq!(UninhabitedEnumKind::iter().count(), 0);
+}
+
+#[test]
+fn test_self_test() {
+    WithSelfTestKind::__assert_kind_invariants();
+}
+
+#[test]
+fn test_with_catch_all() {
+    assert_eq!(
+        WithCatchAllKind::from(WithCatchAll::First(1)),
+        WithCatchAllKind::First
+    );
+    assert_eq!(
+        WithCatchAllKind::from(WithCatchAll::Second),
+        WithCatchAllKind::Other
+    );
+    assert_eq!(
+        WithCatchAllKind::ALL,
+        [WithCatchAllKind::First, WithCatchAllKind::Other]
+    );
+    assert_eq!(
+        serde_json::from_str::<WithCatchAllKind>("\"Second\"").unwrap(),
+        WithCatchAllKind::Other
+    );
+    assert_eq!(
+        serde_json::from_str::<WithCatchAllKind>("\"First\"").unwrap(),
+        WithCatchAllKind::First
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_with_catch_all_serde_feature() {
+    assert_eq!(
+        WithCatchAllDeriveAllKind::from(WithCatchAllDeriveAll::Second),
+        WithCatchAllDeriveAllKind::Unknown
+    );
+    assert_eq!(
+        serde_json::from_str::<WithCatchAllDeriveAllKind>("\"Missing\"").unwrap(),
+        WithCatchAllDeriveAllKind::Unknown
+    );
+}
+
+#[test]
+fn test_kind_map() {
+    let mut map = WithMapKindMap::from_fn(|kind| match kind {
+        WithMapKind::First => "first".to_owned(),
+        WithMapKind::Second => "second".to_owned(),
+        WithMapKind::Third => "third".to_owned(),
+    });
+    assert_eq!(map.get(WithMapKind::First), "first");
+    assert_eq!(map.get(WithMapKind::from(WithMap::Second)), "second");
+    map.get_mut(WithMapKind::Third).push('!');
+    assert_eq!(map.get(WithMapKind::Third), "third!");
+}
+
+#[test]
+fn test_eq_by_name() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashSet;
+    use std::hash::{Hash, Hasher};
+    fn hash(kind: WithEqByNameKind) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        kind.hash(&mut hasher);
+        hasher.finish()
+    }
+    let first = WithEqByNameKind::from(WithEqByName::First(1));
+    assert_eq!(first, WithEqByNameKind::First);
+    assert_ne!(first, WithEqByNameKind::Second);
+    assert_eq!(hash(first), hash(WithEqByNameKind::First));
+    assert!(first < WithEqByNameKind::Second);
+    let mut set = HashSet::new();
+    assert!(set.insert(WithEqByNameKind::First));
+    assert!(!set.insert(first));
+    assert!(set.insert(WithEqByNameKind::Second));
+    #[cfg(not(feature = "no-stdlib"))]
+    assert_eq!(set, WithEqByNameKind::all_hash_set());
+}
+
+#[test]
+fn test_with_non_exhaustive() {
+    use enum_kinds::ToKind;
+    assert_eq!(
+        WithNonExhaustiveKind::from(&WithNonExhaustive::First(1)),
+        WithNonExhaustiveKind::First
+    );
+    assert_eq!(
+        WithNonExhaustive::Third.kind(),
+        WithNonExhaustiveKind::Other
+    );
+    assert_eq!(WithNonExhaustiveKind::COUNT, 3);
+}
+
+#[test]
+fn test_inherent_only() {
+    let value = 10;
+    let first = WithInherentOnly::First(&value);
+    assert_eq!(first.kind(), WithInherentOnlyKind::First);
+    assert_eq!(first.as_kind(), WithInherentOnlyKind::First);
+    let second: WithInherentOnly<u32> = WithInherentOnly::Second;
+    assert_eq!(enum_kinds::kind_of(&second), WithInherentOnlyKind::Second);
+}
+
+#[test]
+fn test_at() {
+    for kind in UnnamedEnumKind::iter() {
+        assert_eq!(UnnamedEnumKind::at(kind.as_index()), Some(kind));
+    }
+    assert_eq!(UnnamedEnumKind::at(UnnamedEnumKind::COUNT), None);
+    assert_eq!(
+        WithIndexTypeKind::at(WithIndexTypeKind::Third.as_index() as usize),
+        Some(WithIndexTypeKind::Third)
+    );
+}
+
+#[test]
+fn test_crate_visible() {
+    use crate_visible::{CrateVisible, CrateVisibleKind, CrateVisibleKindMap};
+    assert_eq!(
+        CrateVisibleKind::from(&CrateVisible::First(1)),
+        CrateVisibleKind::First
+    );
+    assert_eq!(CrateVisibleKind::iter().count(), 2);
+    assert!("Third".parse::<CrateVisibleKind>().is_err());
+    let map = CrateVisibleKindMap::from_fn(|kind| kind.name());
+    assert_eq!(*map.get(CrateVisibleKind::Second), "Second");
+}
+
+#[test]
+fn test_without_debug() {
+    WithoutDebugKind::__assert_kind_invariants();
+    let kind = WithoutDebugKind::from(WithoutDebug::First(1));
+    assert!(kind == WithoutDebugKind::First);
+    assert_eq!(kind.name(), "First");
+    assert_eq!(WithoutDebugKind::iter().count(), 2);
+    let map = WithoutDebugKindMap::from_fn(|kind| kind.as_index());
+    assert_eq!(*map.get(WithoutDebugKind::Second), 1);
+}
+
+#[test]
+fn test_with_groups() {
+    let kind = WithGroupsKind::from(WithGroups::Float(1.0));
+    assert_eq!(WithGroupsGroup::from(kind), WithGroupsGroup::Number);
+    assert_eq!(
+        WithGroupsGroup::from(WithGroupsKind::Integer),
+        WithGroupsGroup::Number
+    );
+    assert_eq!(
+        WithGroupsGroup::from(WithGroupsKind::String),
+        WithGroupsGroup::Text
+    );
+    assert_eq!(
+        WithGroupsGroup::from(WithGroupsKind::from(WithGroups::Nothing)),
+        WithGroupsGroup::Other
+    );
+}
+
+#[test]
+fn test_groups_with_eq_by_name() {
+    let group = WithGroupsByNameGroup::from(WithGroupsByNameKind::Integer);
+    assert_eq!(group, WithGroupsByNameGroup::Number);
+    assert!(group != WithGroupsByNameGroup::Text);
+    assert_eq!(WithGroupsByNameKind::Integer, WithGroupsByNameKind::Integer);
+}
+
+#[test]
+fn test_with_value_keys() {
+    assert_eq!(WithValueKeysKind::Five as u8, 5);
+    assert_eq!(WithValueKeysKind::Six as u8, 6);
+    assert_eq!(WithValueKeysKind::Ten as u8, 10);
+    assert_eq!(WithValueKeysKind::Five.name(), "five");
+    assert_eq!(WithValueKeysKind::Six.name(), "six");
+    assert_eq!(WithValueKeysKind::Ten.name(), "Ten");
+    assert_eq!(WithValueKeysKind::VARIANT_NAMES, ["five", "six", "Ten"]);
+    assert_eq!("five".parse(), Ok(WithValueKeysKind::Five));
+    assert_eq!("V".parse(), Ok(WithValueKeysKind::Five));
+    assert!("Five".parse::<WithValueKeysKind>().is_err());
+}
+
+#[test]
+fn test_from_index() {
+    const SECOND: Option<UnnamedEnumKind> = UnnamedEnumKind::from_index(1);
+    const MISSING: Option<UnnamedEnumKind> = UnnamedEnumKind::from_index(3);
+    assert_eq!(SECOND, Some(UnnamedEnumKind::Second));
+    assert_eq!(MISSING, None);
+    assert_eq!(
+        WithIndexTypeKind::from_index(2),
+        Some(WithIndexTypeKind::Third)
+    );
+    assert_eq!(UninhabitedEnumKind::from_index(0), None);
+}
+
+#[test]
+fn test_with_hash() {
+    use std::collections::HashMap;
+    let mut counts = HashMap::new();
+    for value in &[
+        WithHash::First(1),
+        WithHash::Second(true),
+        WithHash::First(2),
+    ] {
+        *counts.entry(WithHashKind::from(value)).or_insert(0) += 1;
+    }
+    assert_eq!(counts[&WithHashKind::First], 2);
+    assert_eq!(counts[&WithHashKind::Second], 1);
+    #[cfg(not(feature = "no-stdlib"))]
+    {
+        assert_eq!(WithHashKind::all_hash_set().len(), 2);
+        let hashed = WithReprHashKind::all_hash_set();
+        assert_eq!(hashed.len(), WithReprHashKind::COUNT);
+        assert!(hashed.contains(&WithReprHashKind::from(WithReprHash::Second)));
+    }
+}
+
+#[test]
+fn test_variant_docs() {
+    assert_eq!(
+        WithVariantDocsKind::First.variant_docs(),
+        Some("The first variant.\n\nIt has a second paragraph.")
+    );
+    assert_eq!(WithVariantDocsKind::Second.variant_docs(), None);
+}
+
+#[test]
+fn test_macro_stamped_enums() {
+    let first: Vec<StampedFirstKind> = StampedFirstKind::iter().collect();
+    let second: Vec<StampedSecondKind> = StampedSecondKind::iter().collect();
+    assert_eq!(first, StampedFirstKind::ALL);
+    assert_eq!(second, StampedSecondKind::ALL);
+    assert_eq!(
+        "Second".parse::<StampedFirstKind>(),
+        Ok(StampedFirstKind::Second)
+    );
+    assert!("Third".parse::<StampedSecondKind>().is_err());
+    let map = StampedSecondKindMap::from_fn(|kind| kind.as_index());
+    assert_eq!(*map.get(StampedSecondKind::Second), 1);
+    assert_eq!(
+        StampedFirstKind::from(&StampedFirst::First(1)),
+        StampedFirstKind::First
+    );
+    assert_eq!(
+        StampedSecondKind::from(StampedSecond::Second),
+        StampedSecondKind::Second
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_as_index() {
+    let json = serde_json::to_string(&WithSerdeIndexKind::Second).unwrap();
+    assert_eq!(json, "1");
+    assert_eq!(
+        serde_json::from_str::<WithSerdeIndexKind>("0").unwrap(),
+        WithSerdeIndexKind::First
+    );
+    assert!(serde_json::from_str::<WithSerdeIndexKind>("2").is_err());
+    assert!(serde_json::from_str::<WithSerdeIndexKind>("\"First\"").is_err());
+}
+
+#[test]
+fn test_ord_by_declaration() {
+    assert!(WithOrdKind::First < WithOrdKind::Second);
+    assert!(WithOrdKind::First.repr_value() > WithOrdKind::Second.repr_value());
+    #[cfg(not(feature = "no-stdlib"))]
+    {
+        let set: Vec<WithOrdKind> = WithOrdKind::all_set().into_iter().collect();
+        assert_eq!(set, WithOrdKind::ALL);
+    }
+}
+
+#[test]
+fn test_reexported_kinds() {
+    use kinds::*;
+    assert_eq!(
+        ShapeKind::from(&shapes::Shape::Square(1.0)),
+        ShapeKind::Square
+    );
+    let error: ParseShapeKindError = "Triangle".parse::<ShapeKind>().unwrap_err();
+    assert_eq!(error.to_string(), "invalid ShapeKind name");
+}
+
+#[test]
+fn test_toggle() {
+    assert_eq!(WithHashKind::First.toggle(), WithHashKind::Second);
+    assert_eq!(WithHashKind::First.toggle().toggle(), WithHashKind::First);
+    assert_eq!(!WithHashKind::Second, WithHashKind::First);
+    assert_eq!(!!WithoutCopyKind::First, WithoutCopyKind::First);
+}
+
+#[test]
+fn test_renamed_variants() {
+    let kind = WithRenamesKind::from(&WithRenames::Old(1));
+    assert_eq!(kind, WithRenamesKind::New);
+    assert_eq!(kind.name(), "New");
+    assert_eq!(
+        WithRenamesKind::from(WithRenames::Other),
+        WithRenamesKind::Renamed
+    );
+    assert_eq!(serde_json::to_string(&kind).unwrap(), "\"New\"");
+    assert_eq!(
+        serde_json::to_string(&WithRenamesKind::Renamed).unwrap(),
+        "\"custom\""
+    );
+}
+
+#[test]
+fn test_any_kind() {
+    use enum_kinds::KindSliceExt;
+    let values: Vec<WithHash> = (1..3).map(WithHash::First).collect();
+    assert!(values.any_kind(WithHashKind::First));
+    assert!(!values.any_kind(WithHashKind::Second));
+    assert!(!Vec::<WithHash>::new().any_kind(WithHashKind::First));
+}
+
+#[test]
+fn test_transparent_original() {
+    assert_eq!(std::mem::size_of::<Transparent>(), 4);
+    assert_eq!(std::mem::size_of::<TransparentKind>(), 0);
+    assert_eq!(
+        TransparentKind::from(Transparent::Only(1)),
+        TransparentKind::Only
+    );
+}
+
+#[test]
+fn test_kind_count() {
+    use enum_kinds::ToKind;
+    fn kind_count<T: ToKind>() -> usize {
+        T::KIND_COUNT
+    }
+    assert_eq!(kind_count::<WithHash>(), 2);
+    assert_eq!(kind_count::<WithGroups>(), WithGroupsKind::COUNT);
+    let counts = [0u32; <WithHash as ToKind>::KIND_COUNT];
+    assert_eq!(counts.len(), 2);
+}
+
+#[test]
+fn test_display() {
+    fn name_of<S: AsRef<str>>(name: S) -> String {
+        name.as_ref().to_owned()
+    }
+    assert_eq!(name_of(WithDisplayKind::First), "first");
+    assert_eq!(name_of(WithDisplayKind::Second), "Second");
+    assert_eq!(WithDisplayKind::First.to_string(), "first");
+}
+
+#[test]
+fn test_partial_values() {
+    assert_eq!(WithPartialValuesKind::First as u8, 0);
+    assert_eq!(WithPartialValuesKind::Second as u8, 5);
+    assert_eq!(WithPartialValuesKind::Third as u8, 6);
+    assert_eq!(WithPartialValuesKind::Fourth as u8, 10);
+    assert_eq!(WithPartialValuesKind::Fifth as u8, 11);
+    assert_eq!(WithPartialValuesKind::Third.as_index(), 2);
+}
+
+#[cfg(all(feature = "serde_json", not(feature = "no-stdlib")))]
+#[test]
+fn test_to_json_string() {
+    assert_eq!(WithDisplayKind::Second.to_json_string(), "\"Second\"");
+    assert_eq!(WithDisplayKind::First.to_json_string(), "\"first\"");
+    assert_eq!(
+        WithDisplayKind::First.to_json_string(),
+        serde_json::to_string(WithDisplayKind::First.name()).unwrap()
+    );
+}
+
+#[test]
+fn test_attribute_order() {
+    use std::collections::HashSet;
+    let after: HashSet<KindAfterOthersKind> = KindAfterOthersKind::iter().collect();
+    let before: HashSet<KindBeforeOthersKind> = KindBeforeOthersKind::iter().collect();
+    assert_eq!(after.len(), 2);
+    assert_eq!(before.len(), 2);
+    assert_eq!(
+        KindAfterOthersKind::from(KindAfterOthers::First(1).clone()),
+        KindAfterOthersKind::First
+    );
+    assert_eq!(
+        KindBeforeOthersKind::from(&KindBeforeOthers::Second),
+        KindBeforeOthersKind::Second
+    );
+}
+
+#[test]
+fn test_display_conversions() {
+    let name: &'static str = WithDisplayKind::First.into();
+    assert_eq!(name, "first");
+    #[cfg(not(feature = "no-stdlib"))]
+    {
+        let owned: String = WithDisplayKind::Second.into();
+        assert_eq!(owned, "Second");
+    }
+}
+
+#[test]
+fn test_to_kind_and_into_kind() {
+    use enum_kinds::{IntoKind, ToKind};
+    fn borrowed<T: ToKind>(value: &T) -> T::Kind {
+        value.kind()
+    }
+    fn owned<T: IntoKind>(value: T) -> T::Kind {
+        value.into_kind()
+    }
+    assert_eq!(borrowed(&WithHash::First(1)), WithHashKind::First);
+    assert_eq!(owned(WithHash::Second(true)), WithHashKind::Second);
+    assert_eq!(
+        owned(WithInherentOnly::First(&1)),
+        WithInherentOnlyKind::First
+    );
+}
+
+#[test]
+fn test_empty_derive() {
+    use std::collections::HashSet;
+    let kinds: HashSet<WithEmptyDeriveKind> = WithEmptyDeriveKind::iter().collect();
+    assert_eq!(kinds.len(), 2);
+    assert_eq!(
+        WithEmptyDeriveKind::from(WithEmptyDerive::First(1)),
+        WithEmptyDeriveKind::First
+    );
+}
+
+#[test]
+fn test_variant_lints() {
+    use variant_lints::{VariantLints, VariantLintsKind};
+    let kind = VariantLintsKind::from(VariantLints::First(1));
+    assert_eq!(kind, VariantLintsKind::First);
+    assert_eq!(kind.name(), "First");
+}
+
+#[test]
+fn test_name_styles() {
+    assert_eq!(
+        WithSnakeNamesKind::VARIANT_NAMES,
+        ["first_kind", "http_server", "v2"]
+    );
+    assert_eq!(
+        WithKebabNamesKind::VARIANT_NAMES,
+        ["first-kind", "http-server", "v2"]
+    );
+    assert_eq!(
+        WithScreamingSnakeNamesKind::VARIANT_NAMES,
+        ["FIRST_KIND", "HTTP_SERVER", "V2"]
+    );
+    assert_eq!(
+        WithCamelNamesKind::VARIANT_NAMES,
+        ["firstKind", "httpServer", "v2"]
+    );
+    assert_eq!(
+        WithPascalNamesKind::VARIANT_NAMES,
+        ["FirstKind", "HttpServer", "V2"]
+    );
+    assert_eq!(WithScreamingSnakeNamesKind::FirstKind.name(), "FIRST_KIND");
+    assert_eq!(WithKebabNamesKind::HTTPServer.to_string(), "http-server");
+    assert_eq!(
+        "firstKind".parse::<WithCamelNamesKind>(),
+        Ok(WithCamelNamesKind::FirstKind)
+    );
+    assert!("FirstKind".parse::<WithSnakeNamesKind>().is_err());
+}
+
+#[test]
+fn test_try_from_repr() {
+    use std::convert::TryFrom;
+    assert_eq!(WithReprKind::try_from_repr(10), Ok(WithReprKind::Second));
+    let error = WithReprKind::try_from_repr(5).unwrap_err();
+    assert_eq!(error.value(), 5);
+    assert_eq!(
+        error.to_string(),
+        "discriminant 5 is not assigned to any WithReprKind"
+    );
+    let error = UnnamedEnumKind::try_from(7usize).unwrap_err();
+    assert_eq!(error.value(), 7);
+    assert_eq!(
+        error.to_string(),
+        "index 7 is out of range for UnnamedEnumKind"
+    );
+}
+
+#[test]
+fn test_option_and_result_kinds() {
+    use enum_kinds::{OptionKindExt, ResultKindExt};
+    let values = [WithHash::First(1), WithHash::Second(false)];
+    assert_eq!(values.first().map_kind(), Some(WithHashKind::First));
+    assert_eq!(values.get(5).map_kind(), None);
+    assert_eq!(
+        Some(WithHash::Second(true)).map_kind(),
+        Some(WithHashKind::Second)
+    );
+    let ok: Result<WithHash, &str> = Ok(WithHash::First(2));
+    let err: Result<WithHash, &str> = Err("failed");
+    assert_eq!(ok.map_kind(), Ok(WithHashKind::First));
+    assert_eq!(err.map_kind(), Err(&"failed"));
+    assert_eq!(ok.as_kind_opt(), Some(WithHashKind::First));
+    assert_eq!(err.as_kind_opt(), None);
+}
+
+#[test]
+fn test_default_variant() {
+    assert_eq!(
+        WithDefaultVariantKind::default(),
+        WithDefaultVariantKind::Second
+    );
+    assert_eq!(
+        WithImpliedDefaultKind::default(),
+        WithImpliedDefaultKind::Other
+    );
+}
+
+#[test]
+fn test_string_name() {
+    assert_eq!(
+        WithStringNameKind::from(WithStringName::First(1)),
+        WithStringNameKind::First
+    );
+    assert!("Third".parse::<WithStringNameKind>().is_err());
+}
+
+#[test]
+fn test_hash_original_by_kind() {
+    use std::collections::HashMap;
+    let mut cache = HashMap::new();
+    cache.insert(HashedByKind::First(1), "first");
+    cache.insert(HashedByKind::First(2), "replaced");
+    cache.insert(HashedByKind::Second("a".to_owned()), "second");
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache[&HashedByKind::First(3)], "replaced");
+    assert!(HashedByKind::First(1) != HashedByKind::Second("a".to_owned()));
+}
+
+#[test]
+fn test_with_const_bound() {
+    use enum_kinds::{IntoKind, ToKind};
+    let array: WithConstBound<u8, 4> = WithConstBound::Array(Default::default());
+    assert_eq!(WithConstBoundKind::from(&array), WithConstBoundKind::Array);
+    assert_eq!(array.kind(), WithConstBoundKind::Array);
+    let values = [1u16, 2];
+    let slice: WithConstBound<u16, 2> = WithConstBound::Slice(&values);
+    assert_eq!(slice.into_kind(), WithConstBoundKind::Slice);
+    let empty: WithConstBound<char, 1> = WithConstBound::Empty;
+    assert_eq!(WithConstBoundKind::from(empty), WithConstBoundKind::Empty);
+}
+
+#[test]
+fn test_std_discriminant() {
+    let first = WithHash::First(1);
+    assert_eq!(
+        first.std_discriminant(),
+        WithHash::First(2).std_discriminant()
+    );
+    assert_ne!(
+        first.std_discriminant(),
+        WithHash::Second(true).std_discriminant()
+    );
+    assert_eq!(first.std_discriminant(), std::mem::discriminant(&first));
+}
+
+#[test]
+fn test_std_discriminant_with_skipped_variants() {
+    let second = WithSkippedDiscriminants::Second;
+    let third = WithSkippedDiscriminants::Third;
+    assert_eq!(
+        WithSkippedDiscriminantsKind::from(&second),
+        WithSkippedDiscriminantsKind::from(&third)
+    );
+    assert_ne!(second.std_discriminant(), third.std_discriminant());
+}
+
+#[test]
+fn test_own_original_methods() {
+    let first = WithOwnMethods::First(1);
+    assert_eq!(first.std_discriminant(), "own");
+    assert_eq!(WithOwnMethodsKind::from(&first), WithOwnMethodsKind::First);
+    assert!(matches!(first.with_kind(), WithOwnMethods::First(1)));
+    assert!(!WithOwnMethodsKind::Second);
+}
+
+#[test]
+fn test_with_self_reference() {
+    let wrapped = WithSelfReference::Wrapped(WithSelfReferenceKind::Empty);
+    assert_eq!(
+        WithSelfReferenceKind::from(&wrapped),
+        WithSelfReferenceKind::Wrapped
+    );
+}
+
+#[test]
+fn test_with_kind() {
+    let originals = vec![WithHash::First(1), WithHash::Second(false)];
+    let tagged: Vec<(WithHashKind, WithHash)> =
+        originals.into_iter().map(WithHash::with_kind).collect();
+    assert_eq!(tagged.len(), 2);
+    assert!(matches!(
+        tagged[0],
+        (WithHashKind::First, WithHash::First(1))
+    ));
+    assert!(matches!(
+        tagged[1],
+        (WithHashKind::Second, WithHash::Second(false))
+    ));
+}
+
+#[test]
+fn test_renamed_serde_crate() {
+    assert_eq!(
+        serde_json::to_string(&WithRenamedSerdeKind::Second).unwrap(),
+        "\"Second\""
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_renamed_serde_crate_derive_all() {
+    let kind: WithRenamedSerdeDeriveAllKind = serde_json::from_str("\"First\"").unwrap();
+    assert_eq!(kind, WithRenamedSerdeDeriveAllKind::First);
+}
+
+#[test]
+fn test_const_eq() {
+    const fn is_first(kind: WithHashKind) -> bool {
+        kind.const_eq(&WithHashKind::First)
+    }
+    const MATCHES: [bool; 2] = [
+        is_first(WithHashKind::First),
+        is_first(WithHashKind::Second),
+    ];
+    assert_eq!(MATCHES, [true, false]);
+}
+
+#[test]
+fn test_recursive_enum() {
+    use enum_kinds::ToKind;
+    let tree = Tree::Node(
+        Box::new(Tree::Leaf(1)),
+        Box::new(Tree::Children {
+            children: vec![Tree::Leaf(2)],
+        }),
+    );
+    assert_eq!(TreeKind::from(&tree), TreeKind::Node);
+    assert_eq!(tree.kind(), TreeKind::Node);
+    if let Tree::Node(left, right) = tree {
+        assert_eq!(left.kind(), TreeKind::Leaf);
+        assert_eq!(TreeKind::from(*right), TreeKind::Children);
+    }
+}
+
+#[test]
+fn test_kind_set() {
+    let mut set = WithSetKindSet::new();
+    assert!(set.is_empty());
+    assert!(set.insert(WithSetKind::Fourth));
+    assert!(set.insert(WithSetKind::First));
+    assert!(set.insert(WithSetKind::Third));
+    assert!(!set.insert(WithSetKind::First));
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(WithSetKind::Third));
+    assert!(!set.contains(WithSetKind::Second));
+    let kinds: Vec<WithSetKind> = set.into_iter().collect();
+    assert_eq!(
+        kinds,
+        [WithSetKind::First, WithSetKind::Third, WithSetKind::Fourth]
+    );
+    assert_eq!((&set).into_iter().len(), 3);
+    assert!(set.remove(WithSetKind::Third));
+    assert_eq!(format!("{:?}", set), "{First, Fourth}");
+}
+
+#[test]
+fn test_derive_with_helper_attr() {
+    let kind: WithHelperAttrKind = WithHelperAttr::FirstVariant(1).into();
+    assert_eq!(serde_json::to_string(&kind).unwrap(), "\"first_variant\"");
+    assert_eq!(
+        serde_json::from_str::<WithHelperAttrKind>("\"second_variant\"").unwrap(),
+        WithHelperAttrKind::SecondVariant
+    );
+}
+
+#[test]
+fn test_hash_by_repr() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashMap;
+    use std::hash::{Hash, Hasher};
+
+    let mut names = HashMap::new();
+    names.insert(WithReprHashKind::First, "first");
+    names.insert(WithReprHashKind::Second, "second");
+    names.insert(WithReprHashKind::Third, "third");
+    for &kind in &WithReprHashKind::ALL {
+        let rebuilt = WithReprHashKind::try_from_repr(kind.repr_value()).unwrap();
+        assert_eq!(names.get(&rebuilt), names.get(&kind));
+    }
+    assert_eq!(names[&WithReprHash::Second.into()], "second");
+
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+    assert_eq!(hash_of(&WithReprHashKind::Second), hash_of(&7u8));
+}
+
+#[test]
+fn test_mixed_shapes_with_skips() {
+    let cases = [
+        (WithMixedSkips::First(1), WithMixedSkipsKind::First),
+        (WithMixedSkips::Second { x: 2 }, WithMixedSkipsKind::Second),
+        (WithMixedSkips::Third, WithMixedSkipsKind::Third),
+        (WithMixedSkips::Fourth(4, true), WithMixedSkipsKind::Other),
+        (WithMixedSkips::Fifth { y: 'y' }, WithMixedSkipsKind::Other),
+        (WithMixedSkips::Sixth, WithMixedSkipsKind::Other),
+    ];
+    for (value, kind) in &cases {
+        assert_eq!(WithMixedSkipsKind::from(value), *kind);
+    }
+    assert_eq!(WithMixedSkipsKind::COUNT, 4);
+}
+
+#[cfg(feature = "valuable")]
+#[test]
+fn test_valuable() {
+    use valuable::{Enumerable, Valuable, Value, Variant, Visit};
+
+    struct RecordVariant(Option<&'static str>);
+
+    impl Visit for RecordVariant {
+        fn visit_value(&mut self, value: Value<'_>) {
+            if let Value::Enumerable(kind) = value {
+                if let Variant::Static(variant) = kind.variant() {
+                    self.0 = Some(variant.name());
+                }
+            }
+        }
+    }
+
+    let kind = WithValuableKind::from(WithValuable::Second { value: true });
+    let mut record = RecordVariant(None);
+    valuable::visit(&kind, &mut record);
+    assert_eq!(record.0, Some("Second"));
+    let definition = kind.definition();
+    assert_eq!(definition.name(), "WithValuableKind");
+    let variants: Vec<&str> = definition
+        .variants()
+        .iter()
+        .map(|variant| variant.name())
+        .collect();
+    assert_eq!(variants, ["First", "Second"]);
+    assert!(matches!(kind.as_value(), Value::Enumerable(_)));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_random_except() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::seed_from_u64(0x5eed);
+    let mut seen = [false; UnnamedEnumKind::COUNT];
+    for _ in 0..1000 {
+        let kind = UnnamedEnumKind::random_except(&mut rng, UnnamedEnumKind::Second).unwrap();
+        assert_ne!(kind, UnnamedEnumKind::Second);
+        seen[kind.as_index()] = true;
+    }
+    assert_eq!(seen, [true, false, true]);
+    assert_eq!(
+        SingleVariantKind::random_except(&mut rng, SingleVariantKind::Only),
+        None
+    );
+}
+
+#[test]
+fn test_is_uninhabited() {
+    const UNINHABITED: [bool; 3] = [
+        UninhabitedEnumKind::IS_UNINHABITED,
+        UnnamedEnumKind::IS_UNINHABITED,
+        WithCatchAllKind::IS_UNINHABITED,
+    ];
+    assert_eq!(UNINHABITED, [true, false, false]);
+}
+
+#[test]
+fn test_ord_hash_and_serde_together() {
+    use std::collections::{BTreeMap, HashSet};
+    let mut counts = BTreeMap::new();
+    for value in &[
+        WithEverything::Third,
+        WithEverything::First(1),
+        WithEverything::Third,
+    ] {
+        *counts.entry(WithEverythingKind::from(value)).or_insert(0) += 1;
+    }
+    assert_eq!(
+        counts.into_iter().collect::<Vec<_>>(),
+        [
+            (WithEverythingKind::First, 1),
+            (WithEverythingKind::Third, 2)
+        ]
+    );
+    let kinds: HashSet<WithEverythingKind> = WithEverythingKind::iter().collect();
+    assert_eq!(kinds.len(), 3);
+    let json = serde_json::to_string(&WithEverythingKind::Second).unwrap();
+    assert_eq!(
+        serde_json::from_str::<WithEverythingKind>(&json).unwrap(),
+        WithEverythingKind::Second
+    );
+}
+
+#[test]
+fn test_from_raw_index() {
+    let external: [usize; 5] = [0, 2, 3, usize::MAX, 1];
+    let kinds: Vec<Option<UnnamedEnumKind>> = external
+        .iter()
+        .map(|&index| UnnamedEnumKind::from_raw_index(index))
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            Some(UnnamedEnumKind::First),
+            Some(UnnamedEnumKind::Third),
+            None,
+            None,
+            Some(UnnamedEnumKind::Second),
+        ]
+    );
+    assert_eq!(UninhabitedEnumKind::from_raw_index(0), None);
+}
+
+#[test]
+fn test_non_exhaustive_with_discriminants() {
+    assert_eq!(
+        WithNonExhaustiveReprKind::from(&WithNonExhaustiveRepr::Third { value: true }),
+        WithNonExhaustiveReprKind::Third
+    );
+    let values: Vec<u8> = WithNonExhaustiveReprKind::iter()
+        .map(|kind| kind.repr_value())
+        .collect();
+    assert_eq!(values, [10, 11, 20]);
+    assert_eq!(
+        WithNonExhaustiveReprKind::try_from_repr(11),
+        Ok(WithNonExhaustiveReprKind::Second)
+    );
+    assert_eq!(WithNonExhaustiveReprKind::Second as u8, 11);
+}
+
+#[test]
+fn test_kind_name() {
+    use enum_kinds::ToKind;
+    fn describe<T: ToKind>(value: &T) -> String {
+        format!("kind {}", value.kind_name())
+    }
+    assert_eq!(describe(&UnnamedEnum::Second('a')), "kind Second");
+    assert_eq!(describe(&&NamedEnum::Bar { zap: 'b' }), "kind Bar");
+    assert_eq!(describe(&WithDisplay::First(1)), "kind first");
+}
+
+#[test]
+fn test_compare_with_references() {
+    let owned = WithConversionsKind::Second;
+    let borrowed = &WithConversionsKind::Second;
+    assert!(owned == borrowed);
+    assert!(borrowed == owned);
+    let other = &WithConversionsKind::First;
+    assert!(owned != other);
+    assert!(other != owned);
+    assert_eq!(
+        WithConversionsKind::iter()
+            .position(|kind| kind == borrowed)
+            .unwrap(),
+        1
+    );
+}
+
+#[test]
+fn test_into_infers_kind_in_comparisons() {
+    let value = UnnamedEnum::Second('a');
+    assert_eq!(UnnamedEnumKind::Second, (&value).into());
+    assert!(UnnamedEnumKind::Second == (&value).into());
+    assert!(UnnamedEnumKind::Second == value.into());
+}
+
+#[test]
+fn test_indexed_names() {
+    let pairs: Vec<(usize, &str)> = WithDisplayKind::iter()
+        .map(|kind| (kind.as_index(), kind.name()))
+        .collect();
+    assert_eq!(WithDisplayKind::INDEXED_NAMES, pairs.as_slice());
+    assert_eq!(WithDisplayKind::INDEXED_NAMES[0], (0, "first"));
+    assert!(UninhabitedEnumKind::INDEXED_NAMES.is_empty());
+}
+
+#[test]
+fn test_unconventional_variant_names() {
+    use unconventional_names::{Unconventional, UnconventionalKind};
+    assert_eq!(
+        UnconventionalKind::from(Unconventional::snake_case(1)),
+        UnconventionalKind::snake_case
+    );
+    assert_eq!(UnconventionalKind::lower.name(), "lower");
+}
+
+#[test]
+fn test_sequential_repr() {
+    assert_eq!(SequentialReprKind::A as u8, 0);
+    assert_eq!(SequentialReprKind::B as u8, 1);
+    assert_eq!(SequentialReprKind::C as u8, 2);
+    let values: Vec<u8> = SkippedReprKind::iter()
+        .map(|kind| kind.repr_value())
+        .collect();
+    assert_eq!(values, [0, 3, 10, 12, 13]);
+    assert_eq!(SkippedReprKind::D as u8, 3);
+}
+
+#[test]
+fn test_collect_kind_set() {
+    let values = [
+        WithSet::Third(true),
+        WithSet::First(1),
+        WithSet::Third(false),
+        WithSet::Fourth,
+    ];
+    let set: WithSetKindSet = values.iter().map(WithSetKind::from).collect();
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(WithSetKind::First));
+    assert!(!set.contains(WithSetKind::Second));
+    assert!(set.contains(WithSetKind::Third));
+    assert!(set.contains(WithSetKind::Fourth));
+    assert_eq!(set.into_iter().collect::<WithSetKindSet>(), set);
+}
+
+#[test]
+fn test_next_and_previous() {
+    assert_eq!(UnnamedEnumKind::First.next(), Some(UnnamedEnumKind::Second));
+    assert_eq!(UnnamedEnumKind::Third.next(), None);
+    assert_eq!(
+        UnnamedEnumKind::Third.previous(),
+        Some(UnnamedEnumKind::Second)
+    );
+    assert_eq!(UnnamedEnumKind::First.previous(), None);
+}
+
+#[test]
+fn test_next_without_copy() {
+    let first = WithoutCopyKind::First;
+    assert_eq!(first.next(), Some(WithoutCopyKind::Second));
+    assert_eq!(first.next().unwrap().next(), None);
+    assert_eq!(WithoutCopyKind::Second.previous(), Some(first));
+}
+
+#[test]
+fn test_doc_alias() {
+    use doc_alias::{WithDocAlias, WithDocAliasKind};
+    assert_eq!(
+        WithDocAliasKind::from(WithDocAlias::First(1)),
+        WithDocAliasKind::First
+    );
+}
+
+#[test]
+fn test_kind_array() {
+    let mut counts = WithArrayKindArray::from_fn(|kind| kind.as_index() * 10);
+    assert_eq!(counts[WithArrayKind::Third], 20);
+    for value in &[WithArray::Second, WithArray::First(1), WithArray::Second] {
+        counts[WithArrayKind::from(value)] += 1;
+    }
+    assert_eq!(counts.0, [1, 12, 20]);
+    assert_eq!(counts[WithArrayKind::Second], 12);
+}
+
+#[test]
+fn test_in_group() {
+    let numbers: Vec<WithGroupsKind> = WithGroupsKind::in_group("Number").collect();
+    assert_eq!(numbers, [WithGroupsKind::Integer, WithGroupsKind::Float]);
+    assert_eq!(
+        WithGroupsKind::in_group("Other").collect::<Vec<_>>(),
+        [WithGroupsKind::Other]
+    );
+    assert_eq!(WithGroupsKind::in_group("Missing").count(), 0);
+    let small: Vec<WithPartialGroupsKind> = WithPartialGroupsKind::in_group("Small").collect();
+    assert_eq!(
+        small,
+        [WithPartialGroupsKind::Byte, WithPartialGroupsKind::Flag]
+    );
+}
+
+#[test]
+fn test_as_ref_conversion() {
+    fn names<K: AsRef<WithConversionsKind>>(kinds: &[K]) -> Vec<&'static str> {
+        kinds.iter().map(|kind| kind.as_ref().name()).collect()
+    }
+    let kinds = [WithConversionsKind::Second, WithConversionsKind::First];
+    assert_eq!(names(&kinds), ["Second", "First"]);
+}
+
+#[test]
+fn test_debug_with_value() {
+    assert_eq!(format!("{:?}", WithDebugValueKind::First), "First(1)");
+    assert_eq!(
+        format!("{:?}", WithDebugValueKind::from(WithDebugValue::Second)),
+        "Second(7)"
+    );
+}
+
+#[test]
+fn test_kind_set_macro() {
+    const SET: WithSetKindSet = kind_set!(WithSetKind: First | Fourth);
+    assert_eq!(SET.len(), 2);
+    for kind in WithSetKind::iter() {
+        let expected = kind == WithSetKind::First || kind == WithSetKind::Fourth;
+        assert_eq!(SET.contains(kind), expected);
+    }
+    assert!(kind_set!(WithSetKind:).is_empty());
+    assert_eq!(kind_set!(WithSetKind: Second), {
+        let mut set = WithSetKindSet::new();
+        set.insert(WithSetKind::Second);
+        set
+    });
+}
+
+#[test]
+fn test_variants_added_by_other_macros() {
+    assert_eq!(
+        AugmentedKind::ALL,
+        [
+            AugmentedKind::First,
+            AugmentedKind::Second,
+            AugmentedKind::Extra,
+            AugmentedKind::Another,
+        ]
+    );
+    assert_eq!(
+        AugmentedKind::from(Augmented::Another { value: true }),
+        AugmentedKind::Another
+    );
+    assert_eq!(ConditionalKind::VARIANT_NAMES, ["First", "Kept"]);
+}
+
+#[test]
+fn test_owned_only() {
+    use enum_kinds::ToKind;
+    assert_eq!(
+        WithOwnedOnlyKind::from(WithOwnedOnly::First(String::from("moved"))),
+        WithOwnedOnlyKind::First
+    );
+    let value = WithOwnedOnly::Second { value: 1 };
+    assert_eq!(value.kind(), WithOwnedOnlyKind::Second);
+    assert_eq!(
+        WithOwnedOnlyKind::from(WithOwnedOnly::<u8>::Third),
+        WithOwnedOnlyKind::Other
+    );
+}
+
+#[test]
+fn test_try_kind() {
+    assert_eq!(
+        WithFallible::First(1).try_kind(),
+        Ok(WithFallibleKind::First)
+    );
+    assert_eq!(
+        WithFallible::<u8>::Third.try_kind(),
+        Ok(WithFallibleKind::Third)
+    );
+    let error = WithFallible::Second { value: 2 }.try_kind().unwrap_err();
+    assert_eq!(error.variant(), "Second");
+    assert_eq!(error.to_string(), "variant Second has no WithFallibleKind");
+    assert_eq!(
+        WithFallibleKind::ALL,
+        [WithFallibleKind::First, WithFallibleKind::Third]
+    );
+    assert_eq!(
+        WithFallibleCatchAll::Second.try_kind(),
+        Ok(WithFallibleCatchAllKind::Other)
+    );
+}
+
+#[test]
+fn test_array_sized_by_all() {
+    let mut totals = [0u32; UnnamedEnumKind::ALL.len()];
+    let values = [
+        UnnamedEnum::Third,
+        UnnamedEnum::Second('a'),
+        UnnamedEnum::Third,
+    ];
+    for value in &values {
+        totals[UnnamedEnumKind::from(value).as_index()] += 1;
+    }
+    assert_eq!(totals, [0, 1, 2]);
+}
+
+#[test]
+fn test_mirror_repr() {
+    use std::mem::size_of;
+    assert_eq!(size_of::<FieldlessKind>(), size_of::<Fieldless>());
+    for (original, kind) in [
+        (Fieldless::First, FieldlessKind::First),
+        (Fieldless::Second, FieldlessKind::Second),
+        (Fieldless::Third, FieldlessKind::Third),
+    ] {
+        assert_eq!(original as i16, kind as i16);
+        assert_eq!(kind.repr_value(), original as i16);
+        // The layouts match, so converting between the two is sound.
+        let transmuted: FieldlessKind = unsafe { std::mem::transmute(original) };
+        assert_eq!(transmuted, kind);
+    }
+}
+
+#[test]
+fn test_variant_predicates() {
+    const FIRST: bool = WithPredicatesKind::First.is_first();
+    let first = FIRST;
+    assert!(first);
+    let kind = WithPredicatesKind::from(WithPredicates::SecondVariant { value: true });
+    assert!(kind.is_second_variant());
+    assert!(!kind.is_first());
+    assert!(WithPredicatesKind::HTTPRequest.is_http_request());
+    assert!(WithPredicatesKind::from(WithPredicates::Skipped).is_other());
 }
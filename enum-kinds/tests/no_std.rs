@@ -0,0 +1,118 @@
+#![no_std]
+
+#[cfg(not(feature = "no-stdlib"))]
+extern crate std;
+
+#[macro_use]
+extern crate enum_kinds;
+
+use core::fmt;
+
+#[derive(EnumKind)]
+#[enum_kind(NoStdKind)]
+#[allow(dead_code)]
+enum NoStd {
+    First(u32),
+    Second,
+}
+
+#[derive(EnumKind)]
+#[enum_kind(NoStdDebugKind, repr = "u8", debug_with_value, set)]
+#[allow(dead_code)]
+enum NoStdDebug {
+    First(u32),
+    #[enum_kind_value(5)]
+    Second,
+}
+
+#[derive(Debug)]
+enum ApplicationError {
+    Kind(ParseNoStdKindError),
+}
+
+impl From<ParseNoStdKindError> for ApplicationError {
+    fn from(error: ParseNoStdKindError) -> Self {
+        ApplicationError::Kind(error)
+    }
+}
+
+impl fmt::Display for ApplicationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("application error")
+    }
+}
+
+impl core::error::Error for ApplicationError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ApplicationError::Kind(error) => Some(error),
+        }
+    }
+}
+
+fn parse(name: &str) -> Result<NoStdKind, ApplicationError> {
+    Ok(name.parse()?)
+}
+
+#[test]
+fn test_parse_error_source() {
+    assert_eq!(parse("Second").unwrap(), NoStdKind::Second);
+    let error = parse("Third").unwrap_err();
+    assert!(core::error::Error::source(&error).is_some());
+}
+
+struct Buffer {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl fmt::Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.bytes.len() {
+            return Err(fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_parse_error_without_allocation() {
+    use core::fmt::Write;
+    let error = "Third".parse::<NoStdKind>().unwrap_err();
+    let mut buffer = Buffer {
+        bytes: [0; 64],
+        len: 0,
+    };
+    write!(buffer, "{}", error).unwrap();
+    assert_eq!(
+        core::str::from_utf8(&buffer.bytes[..buffer.len]).unwrap(),
+        "invalid NoStdKind name"
+    );
+}
+
+fn format_into<'a>(buffer: &'a mut Buffer, value: &dyn fmt::Debug) -> &'a str {
+    use core::fmt::Write;
+    buffer.len = 0;
+    write!(buffer, "{:?}", value).unwrap();
+    core::str::from_utf8(&buffer.bytes[..buffer.len]).unwrap()
+}
+
+#[test]
+fn test_debug_without_std() {
+    let mut buffer = Buffer {
+        bytes: [0; 64],
+        len: 0,
+    };
+    assert_eq!(format_into(&mut buffer, &NoStdKind::First), "First");
+    assert_eq!(
+        format_into(&mut buffer, &NoStdDebugKind::Second),
+        "Second(5)"
+    );
+    let mut set = NoStdDebugKindSet::new();
+    set.insert(NoStdDebugKind::First);
+    set.insert(NoStdDebugKind::Second);
+    assert_eq!(format_into(&mut buffer, &set), "{First(0), Second(5)}");
+}
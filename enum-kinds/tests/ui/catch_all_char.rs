@@ -0,0 +1,14 @@
+#[macro_use]
+extern crate enum_kinds;
+
+#[derive(EnumKind)]
+#[enum_kind(WithCatchAllCharKind, catch_all)]
+#[allow(dead_code)]
+enum WithCatchAllChar {
+    #[enum_kind_char('F')]
+    First(u32),
+    #[enum_kind_skip]
+    Skipped,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate enum_kinds;
+
+#[derive(EnumKind)]
+#[enum_kind(WithCyclicBoundKind, bound = "WithCyclicBoundKind: Copy")]
+#[allow(dead_code)]
+enum WithCyclicBound {
+    First(u32),
+    Second,
+}
+
+fn main() {}
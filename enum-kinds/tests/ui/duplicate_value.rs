@@ -0,0 +1,15 @@
+#[macro_use]
+extern crate enum_kinds;
+
+#[derive(EnumKind)]
+#[enum_kind(WithDuplicateValueKind)]
+#[allow(dead_code)]
+enum WithDuplicateValue {
+    #[enum_kind_value(1)]
+    First(u32),
+    Second,
+    #[enum_kind_value(2)]
+    Third,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate enum_kinds;
+
+#[derive(EnumKind)]
+#[enum_kind(WithGenericNameKind<T>)]
+#[allow(dead_code)]
+enum WithGenericName<T> {
+    First(T),
+    Second,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#[macro_use]
+extern crate enum_kinds;
+
+#[derive(EnumKind)]
+#[enum_kind(WithInherentOnlyKind, inherent_only)]
+#[allow(dead_code)]
+enum WithInherentOnly {
+    First(u32),
+    Second,
+}
+
+fn main() {
+    let _ = WithInherentOnlyKind::from(WithInherentOnly::Second);
+}
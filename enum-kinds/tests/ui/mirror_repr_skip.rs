@@ -0,0 +1,15 @@
+#[macro_use]
+extern crate enum_kinds;
+
+#[derive(EnumKind)]
+#[enum_kind(WithMirroredSkipKind, mirror_repr, fallible)]
+#[allow(dead_code)]
+#[repr(u8)]
+enum WithMirroredSkip {
+    First = 1,
+    #[enum_kind_skip]
+    Second = 2,
+    Third = 3,
+}
+
+fn main() {}
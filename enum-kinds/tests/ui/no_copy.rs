@@ -0,0 +1,16 @@
+#[macro_use]
+extern crate enum_kinds;
+
+#[derive(EnumKind)]
+#[enum_kind(WithoutCopyKind, no_copy)]
+#[allow(dead_code)]
+enum WithoutCopy {
+    First(u32),
+    Second,
+}
+
+fn requires_copy<T: Copy>(_: T) {}
+
+fn main() {
+    requires_copy(WithoutCopyKind::Second);
+}
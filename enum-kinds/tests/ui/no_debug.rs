@@ -0,0 +1,14 @@
+#[macro_use]
+extern crate enum_kinds;
+
+#[derive(EnumKind)]
+#[enum_kind(WithoutDebugKind, no_debug)]
+#[allow(dead_code)]
+enum WithoutDebug {
+    First(u32),
+    Second,
+}
+
+fn main() {
+    let _ = format!("{:?}", WithoutDebugKind::Second);
+}
@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate enum_kinds;
+
+#[derive(EnumKind)]
+#[enum_kind(WithOrdConflictKind, ord, derive(PartialOrd, Ord))]
+#[allow(dead_code)]
+enum WithOrdConflict {
+    First(u32),
+    Second,
+}
+
+fn main() {}
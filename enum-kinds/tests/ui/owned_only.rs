@@ -0,0 +1,15 @@
+#[macro_use]
+extern crate enum_kinds;
+
+#[derive(EnumKind)]
+#[enum_kind(WithOwnedOnlyKind, owned_only)]
+#[allow(dead_code)]
+enum WithOwnedOnly {
+    First(u32),
+    Second,
+}
+
+fn main() {
+    let _ = WithOwnedOnlyKind::from(WithOwnedOnly::Second);
+    let _ = WithOwnedOnlyKind::from(&WithOwnedOnly::Second);
+}
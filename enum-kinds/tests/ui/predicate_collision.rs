@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate enum_kinds;
+
+#[derive(EnumKind)]
+#[enum_kind(WithPredicateCollisionKind, predicates)]
+#[allow(dead_code, non_camel_case_types)]
+enum WithPredicateCollision {
+    FooBar(u32),
+    Foo_Bar,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate enum_kinds;
+
+#[derive(EnumKind)]
+#[enum_kind(WithReexportPathKind, reexport_path = "crate::kinds")]
+#[allow(dead_code)]
+enum WithReexportPath {
+    First(u32),
+    Second,
+}
+
+fn main() {}
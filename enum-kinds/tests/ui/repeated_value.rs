@@ -0,0 +1,14 @@
+#[macro_use]
+extern crate enum_kinds;
+
+#[derive(EnumKind)]
+#[enum_kind(WithRepeatedValueKind)]
+#[allow(dead_code)]
+enum WithRepeatedValue {
+    #[enum_kind_value(1)]
+    #[enum_kind_value(label = "first")]
+    First(u32),
+    Second,
+}
+
+fn main() {}